@@ -0,0 +1,249 @@
+//! Structure of arrays math for large batches of vectors. `Vector3x4` holds four vectors with
+//! each component in its own array, so the same operation on the four lanes compiles to SIMD
+//! instructions. The slice functions run on whole arrays, four elements at a time.
+
+use crate::matrix::Matrix;
+use crate::vector::{Vector, Vector3};
+use core::ops::*;
+
+/// Four `Vector3`s, one per lane.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Vector3x4 {
+    pub x: [f32; 4],
+    pub y: [f32; 4],
+    pub z: [f32; 4],
+}
+
+/// Applies `f` to each lane.
+#[inline(always)]
+fn lanes(f: impl Fn(usize) -> f32) -> [f32; 4] {
+    [f(0), f(1), f(2), f(3)]
+}
+
+impl Vector3x4 {
+    pub const ZERO: Self = Vector3x4::splat(Vector3::ZERO);
+
+    /// Same vector in every lane.
+    pub const fn splat(v: Vector3) -> Self {
+        Vector3x4 {
+            x: [v.x; 4],
+            y: [v.y; 4],
+            z: [v.z; 4],
+        }
+    }
+
+    pub fn from_array(v: [Vector3; 4]) -> Self {
+        Vector3x4::from_slice(&v)
+    }
+
+    /// The first four vectors of `v`. Panics if it is shorter.
+    pub fn from_slice(v: &[Vector3]) -> Self {
+        let v = &v[..4];
+        Vector3x4 {
+            x: lanes(|i| v[i].x),
+            y: lanes(|i| v[i].y),
+            z: lanes(|i| v[i].z),
+        }
+    }
+
+    pub fn to_array(&self) -> [Vector3; 4] {
+        let mut v = [Vector3::ZERO; 4];
+        self.write_to_slice(&mut v);
+        v
+    }
+
+    /// Writes the lanes to the first four elements of `v`. Panics if it is shorter.
+    pub fn write_to_slice(&self, v: &mut [Vector3]) {
+        for (i, v) in v[..4].iter_mut().enumerate() {
+            *v = Vector3::new(self.x[i], self.y[i], self.z[i]);
+        }
+    }
+
+    pub fn lane(&self, i: usize) -> Vector3 {
+        Vector3::new(self.x[i], self.y[i], self.z[i])
+    }
+
+    pub fn dot(&self, other: &Self) -> [f32; 4] {
+        lanes(|i| self.x[i] * other.x[i] + self.y[i] * other.y[i] + self.z[i] * other.z[i])
+    }
+
+    pub fn cross(&self, other: &Self) -> Self {
+        Vector3x4 {
+            x: lanes(|i| self.y[i] * other.z[i] - self.z[i] * other.y[i]),
+            y: lanes(|i| self.z[i] * other.x[i] - self.x[i] * other.z[i]),
+            z: lanes(|i| self.x[i] * other.y[i] - self.y[i] * other.x[i]),
+        }
+    }
+
+    pub fn length_sq(&self) -> [f32; 4] {
+        self.dot(self)
+    }
+
+    /// `Vector3::transform` of every lane: transforms points, with an implicit w of 1.
+    pub fn transform(&self, matrix: &Matrix) -> Self {
+        let m = matrix;
+        let row = |c: usize| {
+            lanes(|i| self.x[i] * m[0][c] + self.y[i] * m[1][c] + self.z[i] * m[2][c] + m[3][c])
+        };
+        Vector3x4 {
+            x: row(0),
+            y: row(1),
+            z: row(2),
+        }
+    }
+
+    /// Transforms directions, ignoring the translation of `matrix`.
+    pub fn transform_normal(&self, matrix: &Matrix) -> Self {
+        let m = matrix;
+        let row =
+            |c: usize| lanes(|i| self.x[i] * m[0][c] + self.y[i] * m[1][c] + self.z[i] * m[2][c]);
+        Vector3x4 {
+            x: row(0),
+            y: row(1),
+            z: row(2),
+        }
+    }
+}
+
+macro_rules! lanewise_op {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $op:tt) => {
+        impl $trait for Vector3x4 {
+            type Output = Vector3x4;
+            fn $fn(self, rhs: Vector3x4) -> Vector3x4 {
+                Vector3x4 {
+                    x: lanes(|i| self.x[i] $op rhs.x[i]),
+                    y: lanes(|i| self.y[i] $op rhs.y[i]),
+                    z: lanes(|i| self.z[i] $op rhs.z[i]),
+                }
+            }
+        }
+
+        impl $trait<f32> for Vector3x4 {
+            type Output = Vector3x4;
+            fn $fn(self, rhs: f32) -> Vector3x4 {
+                Vector3x4 {
+                    x: lanes(|i| self.x[i] $op rhs),
+                    y: lanes(|i| self.y[i] $op rhs),
+                    z: lanes(|i| self.z[i] $op rhs),
+                }
+            }
+        }
+
+        impl $assign_trait for Vector3x4 {
+            fn $assign_fn(&mut self, rhs: Vector3x4) {
+                *self = *self $op rhs;
+            }
+        }
+
+        impl $assign_trait<f32> for Vector3x4 {
+            fn $assign_fn(&mut self, rhs: f32) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+lanewise_op!(Add, add, AddAssign, add_assign, +);
+lanewise_op!(Sub, sub, SubAssign, sub_assign, -);
+lanewise_op!(Mul, mul, MulAssign, mul_assign, *);
+lanewise_op!(Div, div, DivAssign, div_assign, /);
+
+impl Neg for Vector3x4 {
+    type Output = Vector3x4;
+    fn neg(self) -> Vector3x4 {
+        self * -1.0
+    }
+}
+
+impl From<[Vector3; 4]> for Vector3x4 {
+    fn from(v: [Vector3; 4]) -> Self {
+        Vector3x4::from_array(v)
+    }
+}
+
+impl From<Vector3x4> for [Vector3; 4] {
+    fn from(v: Vector3x4) -> Self {
+        v.to_array()
+    }
+}
+
+/// Runs `f` on `input` four elements at a time through `Vector3x4`, and `single` on the rest.
+fn map_points(
+    input: &[Vector3],
+    output: &mut [Vector3],
+    f: impl Fn(&Vector3x4) -> Vector3x4,
+    single: impl Fn(&Vector3) -> Vector3,
+) {
+    assert_eq!(input.len(), output.len(), "one output per input");
+    let mut input_chunks = input.chunks_exact(4);
+    let mut output_chunks = output.chunks_exact_mut(4);
+    for (input, output) in (&mut input_chunks).zip(&mut output_chunks) {
+        f(&Vector3x4::from_slice(input)).write_to_slice(output);
+    }
+    for (input, output) in input_chunks
+        .remainder()
+        .iter()
+        .zip(output_chunks.into_remainder())
+    {
+        *output = single(input);
+    }
+}
+
+/// `Vector3::transform` of every point of `input`, into `output`.
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn transform_points(matrix: &Matrix, input: &[Vector3], output: &mut [Vector3]) {
+    map_points(
+        input,
+        output,
+        |v| v.transform(matrix),
+        |v| v.transform(matrix),
+    );
+}
+
+/// Transforms every direction of `input` into `output`, ignoring the translation of `matrix`.
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn transform_normals(matrix: &Matrix, input: &[Vector3], output: &mut [Vector3]) {
+    map_points(
+        input,
+        output,
+        |v| v.transform_normal(matrix),
+        |v| v.to_homogeneous_dir().transform(matrix).truncate(),
+    );
+}
+
+/// Dot product of each pair of `a` and `b`, into `output`.
+///
+/// Panics if the slices have different lengths.
+pub fn dot_many(a: &[Vector3], b: &[Vector3], output: &mut [f32]) {
+    assert!(
+        a.len() == b.len() && a.len() == output.len(),
+        "one output per pair"
+    );
+    let mut a_chunks = a.chunks_exact(4);
+    let mut b_chunks = b.chunks_exact(4);
+    let mut output_chunks = output.chunks_exact_mut(4);
+    for ((a, b), output) in (&mut a_chunks).zip(&mut b_chunks).zip(&mut output_chunks) {
+        let dot = Vector3x4::from_slice(a).dot(&Vector3x4::from_slice(b));
+        output.copy_from_slice(&dot);
+    }
+    for ((a, b), output) in a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .zip(output_chunks.into_remainder())
+    {
+        *output = a.dot(b);
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support {
+    use super::Vector3x4;
+    use bytemuck::{Pod, Zeroable};
+
+    unsafe impl Zeroable for Vector3x4 {}
+    unsafe impl Pod for Vector3x4 {}
+}
@@ -0,0 +1,223 @@
+use crate::math;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::scalar::{self, Scalar};
+use crate::vector::{Vector, Vector2, Vector3};
+use core::f32::consts::FRAC_PI_2;
+
+/// Pitch limit of `Camera::fly` and `OrbitCamera::orbit`, just short of straight up or down.
+const MAX_PITCH: f32 = FRAC_PI_2 - 0.01;
+
+/// Perspective camera. Like view space, the camera looks down its local -z axis with y up, and
+/// `rotation` turns that into world space.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Camera {
+    pub position: Vector3,
+    pub rotation: Quaternion,
+    /// Vertical field of view, in radians.
+    pub fov: f32,
+    /// Width / Height
+    pub aspect: f32,
+    pub near_z: f32,
+    pub far_z: f32,
+}
+
+impl Camera {
+    /// Camera at `position`, looking down the world -z axis.
+    pub fn new(position: Vector3, fov: f32, aspect: f32, near_z: f32, far_z: f32) -> Self {
+        Camera {
+            position,
+            rotation: Quaternion::IDENTITY,
+            fov,
+            aspect,
+            near_z,
+            far_z,
+        }
+    }
+
+    pub fn forward(&self) -> Vector3 {
+        self.rotation.rotate(&-Vector3::Z)
+    }
+
+    pub fn right(&self) -> Vector3 {
+        self.rotation.rotate(&Vector3::X)
+    }
+
+    pub fn up(&self) -> Vector3 {
+        self.rotation.rotate(&Vector3::Y)
+    }
+
+    /// `Matrix::look_to` from the position along `forward`.
+    pub fn view(&self) -> Matrix {
+        Matrix::look_to(self.position, self.forward(), self.up())
+    }
+
+    /// `Matrix::perspective_fov` with the camera's lens.
+    pub fn projection(&self) -> Matrix {
+        Matrix::perspective_fov(self.fov, self.aspect, self.near_z, self.far_z)
+    }
+
+    pub fn view_projection(&self) -> Matrix {
+        self.view() * self.projection()
+    }
+
+    /// Turns the camera towards `focus`, with `up` as a hint for the vertical direction.
+    pub fn look_at(&mut self, focus: Vector3, up: Vector3) {
+        // The transposed view rotation maps camera space to world space
+        let view = Matrix::look_to(Vector3::ZERO, focus - self.position, up);
+        self.rotation = Quaternion::from_rotation_matrix(&view.transpose());
+    }
+
+    /// Rotation after turning by `yaw` around the world y axis and by `pitch` around the
+    /// camera's x axis, keeping the pitch within `MAX_PITCH`.
+    fn turned(&self, yaw: f32, pitch: f32) -> Quaternion {
+        let current = scalar::asin(self.forward().y);
+        let pitch = (current + pitch).clamp(-MAX_PITCH, MAX_PITCH) - current;
+        let yaw = Quaternion::from_axis_angle(Vector3::Y, yaw);
+        let pitch = Quaternion::from_axis_angle(Vector3::X, pitch);
+        (pitch * self.rotation * yaw).normalize()
+    }
+
+    /// First person update. Turns by `yaw` and `pitch` radians, positive turning left and
+    /// looking up, then moves by `movement` along the new right, up and forward directions.
+    /// Never rolls, and stops short of looking straight up or down.
+    pub fn fly(&mut self, movement: Vector3, yaw: f32, pitch: f32) {
+        self.rotation = self.turned(yaw, pitch);
+        self.position +=
+            self.right() * movement.x + self.up() * movement.y + self.forward() * movement.z;
+    }
+
+    /// Half of the vertical and horizontal field of view.
+    fn half_fov(&self) -> (f32, f32) {
+        let tan = Scalar::tan(0.5 * self.fov);
+        (tan, tan * self.aspect)
+    }
+
+    /// Moves the camera back along its forward direction until the sphere just fits in view,
+    /// looking at its center. Keeps the orientation and the lens.
+    pub fn fit_sphere(&mut self, center: Vector3, radius: f32) {
+        let (tan_y, tan_x) = self.half_fov();
+        let tan = tan_y.min(tan_x);
+        // sin of the narrower half angle, from its tangent
+        let sin = tan / math::sqrt(1.0 + tan * tan);
+        self.position = center - self.forward() * (radius / sin);
+    }
+
+    /// Moves the camera back along its forward direction until the axis aligned box just fits in
+    /// view, looking at its center. Tighter than fitting its bounding sphere. Keeps the
+    /// orientation and the lens.
+    pub fn fit_box(&mut self, min: Vector3, max: Vector3) {
+        let (tan_y, tan_x) = self.half_fov();
+        let (right, up, forward) = (self.right(), self.up(), self.forward());
+        let center = (min + max) * 0.5;
+        let extent = (max - min) * 0.5;
+
+        // Each corner needs to be at a depth of at least its offset over the tangent
+        let mut distance = 0.0f32;
+        for i in 0..8 {
+            let corner = Vector3::new(
+                if i & 1 == 0 { -extent.x } else { extent.x },
+                if i & 2 == 0 { -extent.y } else { extent.y },
+                if i & 4 == 0 { -extent.z } else { extent.z },
+            );
+            let depth = corner.dot(&forward);
+            distance = distance
+                .max(corner.dot(&right).abs() / tan_x - depth)
+                .max(corner.dot(&up).abs() / tan_y - depth);
+        }
+        self.position = center - forward * distance;
+    }
+}
+
+/// Camera circling around a target, for model viewers and editors. The camera keeps looking at
+/// `target`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct OrbitCamera {
+    pub camera: Camera,
+    pub target: Vector3,
+}
+
+impl OrbitCamera {
+    /// Turns `camera` towards `target`, keeping its position.
+    pub fn new(mut camera: Camera, target: Vector3) -> Self {
+        camera.look_at(target, Vector3::Y);
+        OrbitCamera { camera, target }
+    }
+
+    pub fn distance(&self) -> f32 {
+        (self.camera.position - self.target).length()
+    }
+
+    pub fn view(&self) -> Matrix {
+        self.camera.view()
+    }
+
+    pub fn projection(&self) -> Matrix {
+        self.camera.projection()
+    }
+
+    /// Rotates the camera around the target by `rotation`, in world space.
+    fn rotate_around_target(&mut self, rotation: Quaternion) {
+        let offset = self.camera.position - self.target;
+        self.camera.position = self.target + rotation.rotate(&offset);
+        self.camera.rotation = (self.camera.rotation * rotation).normalize();
+    }
+
+    /// Turntable update. The view turns like `Camera::fly`, so positive `yaw` moves the camera
+    /// to its right around the target, and positive `pitch` moves it down to look up. Stops
+    /// short of the poles.
+    pub fn orbit(&mut self, yaw: f32, pitch: f32) {
+        let turned = self.camera.turned(yaw, pitch);
+        self.rotate_around_target(self.camera.rotation.conjugate() * turned);
+    }
+
+    /// Arcball update for a drag from `from` to `to`, in normalized device coordinates. The
+    /// target turns with the drag, as if the cursor held a ball around it.
+    pub fn arcball(&mut self, from: Vector2, to: Vector2) {
+        let project = |p: Vector2| {
+            let length_sq = p.length_sq();
+            if length_sq <= 1.0 {
+                Vector3::new(p.x, p.y, math::sqrt(1.0 - length_sq))
+            } else {
+                p.extend(0.0).normalize()
+            }
+        };
+        let (a, b) = (project(from), project(to));
+        let axis = a.cross(&b);
+        if axis.length_sq() < 1e-12 {
+            return;
+        }
+
+        // Turning the camera the other way makes the target follow the cursor
+        let angle = Scalar::atan2(axis.length(), a.dot(&b));
+        let axis = self.camera.rotation.rotate(&axis);
+        self.rotate_around_target(Quaternion::from_axis_angle(axis, -angle));
+    }
+
+    /// Multiplies the distance to the target by `factor`.
+    pub fn zoom(&mut self, factor: f32) {
+        let offset = self.camera.position - self.target;
+        self.camera.position = self.target + offset * factor;
+    }
+
+    /// Moves the camera and the target along the view plane, by `delta` times the distance to
+    /// the target, so panning feels the same at any zoom.
+    pub fn pan(&mut self, delta: Vector2) {
+        let distance = self.distance();
+        let offset = (self.camera.right() * delta.x + self.camera.up() * delta.y) * distance;
+        self.camera.position += offset;
+        self.target += offset;
+    }
+
+    /// `Camera::fit_sphere`, then orbits around the center of the sphere.
+    pub fn fit_sphere(&mut self, center: Vector3, radius: f32) {
+        self.camera.fit_sphere(center, radius);
+        self.target = center;
+    }
+
+    /// `Camera::fit_box`, then orbits around the center of the box.
+    pub fn fit_box(&mut self, min: Vector3, max: Vector3) {
+        self.camera.fit_box(min, max);
+        self.target = (min + max) * 0.5;
+    }
+}
@@ -0,0 +1,292 @@
+use crate::math;
+use crate::packed;
+use crate::vector::{Vector, Vector3, Vector4};
+use core::ops::*;
+
+/// RGBA color with float channels, usually in [0, 1].
+///
+/// Unless noted otherwise, the color functions only touch r, g and b, and keep alpha as is.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+/// Named colors, sRGB encoded, same values as `DirectX::Colors`.
+impl Color {
+    pub const TRANSPARENT: Color = Color::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Color = Color::new(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Color = Color::new(1.0, 1.0, 1.0, 1.0);
+    pub const RED: Color = Color::new(1.0, 0.0, 0.0, 1.0);
+    /// Pure green. `GREEN` is the darker web color.
+    pub const LIME: Color = Color::new(0.0, 1.0, 0.0, 1.0);
+    pub const GREEN: Color = Color::new(0.0, 128.0 / 255.0, 0.0, 1.0);
+    pub const BLUE: Color = Color::new(0.0, 0.0, 1.0, 1.0);
+    pub const YELLOW: Color = Color::new(1.0, 1.0, 0.0, 1.0);
+    pub const CYAN: Color = Color::new(0.0, 1.0, 1.0, 1.0);
+    pub const MAGENTA: Color = Color::new(1.0, 0.0, 1.0, 1.0);
+    pub const GRAY: Color = Color::new(128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0, 1.0);
+    pub const SILVER: Color = Color::new(192.0 / 255.0, 192.0 / 255.0, 192.0 / 255.0, 1.0);
+    pub const ORANGE: Color = Color::new(1.0, 165.0 / 255.0, 0.0, 1.0);
+    pub const CORNFLOWER_BLUE: Color = Color::new(100.0 / 255.0, 149.0 / 255.0, 237.0 / 255.0, 1.0);
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        math::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * math::powf(c, 1.0 / 2.4) - 0.055
+    }
+}
+
+/// Hue in [0, 1) of a color with the given channel maximum and (nonzero) chroma.
+fn hue(r: f32, g: f32, b: f32, max: f32, chroma: f32) -> f32 {
+    let sector = if r == max {
+        (g - b) / chroma
+    } else if g == max {
+        2.0 + (b - r) / chroma
+    } else {
+        4.0 + (r - g) / chroma
+    };
+
+    if sector < 0.0 {
+        sector / 6.0 + 1.0
+    } else {
+        sector / 6.0
+    }
+}
+
+/// r, g, b of a fully saturated color with the given hue, scaled by chroma and lifted by m.
+fn from_hue(h: f32, chroma: f32, m: f32) -> (f32, f32, f32) {
+    let h = (h - math::floor(h)) * 6.0;
+    let x = chroma * (1.0 - ((h % 2.0) - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    (r + m, g + m, b + m)
+}
+
+impl Color {
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Color { r, g, b, a }
+    }
+
+    /// Opaque color.
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Color::new(r, g, b, 1.0)
+    }
+
+    fn map_rgb(&self, f: impl Fn(f32) -> f32) -> Self {
+        Color::new(f(self.r), f(self.g), f(self.b), self.a)
+    }
+
+    /// `XMColorSRGBToRGB`. Decodes r, g and b with the exact piecewise sRGB curve, after clamping
+    /// them to [0, 1]. Alpha is linear in both spaces and passes through.
+    pub fn srgb_to_rgb(&self) -> Self {
+        self.map_rgb(|x| srgb_to_linear(x.clamp(0.0, 1.0)))
+    }
+
+    /// `XMColorRGBToSRGB`. Encodes r, g and b with the exact piecewise sRGB curve, after clamping
+    /// them to [0, 1]. Alpha is linear in both spaces and passes through.
+    pub fn rgb_to_srgb(&self) -> Self {
+        self.map_rgb(|x| linear_to_srgb(x.clamp(0.0, 1.0)))
+    }
+
+    /// `XMColorRGBToHSL`. Returns hue, saturation and lightness in r, g and b, all in [0, 1].
+    pub fn rgb_to_hsl(&self) -> Self {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+        let l = (max + min) * 0.5;
+
+        if chroma <= f32::EPSILON {
+            return Color::new(0.0, 0.0, l, self.a);
+        }
+
+        let s = if l <= 0.5 {
+            chroma / (max + min)
+        } else {
+            chroma / (2.0 - max - min)
+        };
+        let h = hue(self.r, self.g, self.b, max, chroma);
+        Color::new(h, s, l, self.a)
+    }
+
+    /// `XMColorHSLToRGB`. Inverse of `rgb_to_hsl`.
+    pub fn hsl_to_rgb(&self) -> Self {
+        let (h, s, l) = (self.r, self.g, self.b);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let (r, g, b) = from_hue(h, chroma, l - chroma * 0.5);
+        Color::new(r, g, b, self.a)
+    }
+
+    /// `XMColorRGBToHSV`. Returns hue, saturation and value in r, g and b, all in [0, 1].
+    pub fn rgb_to_hsv(&self) -> Self {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let chroma = max - min;
+
+        if chroma <= f32::EPSILON {
+            return Color::new(0.0, 0.0, max, self.a);
+        }
+
+        let s = chroma / max;
+        let h = hue(self.r, self.g, self.b, max, chroma);
+        Color::new(h, s, max, self.a)
+    }
+
+    /// `XMColorHSVToRGB`. Inverse of `rgb_to_hsv`.
+    pub fn hsv_to_rgb(&self) -> Self {
+        let (h, s, v) = (self.r, self.g, self.b);
+        let chroma = v * s;
+        let (r, g, b) = from_hue(h, chroma, v - chroma);
+        Color::new(r, g, b, self.a)
+    }
+
+    /// `XMColorRGBToYUV`, BT.601. Returns Y, U and V in r, g and b.
+    pub fn rgb_to_yuv(&self) -> Self {
+        let c = self.to_vector3();
+        let y = c.dot(&Vector3::new(0.299, 0.587, 0.114));
+        let u = c.dot(&Vector3::new(-0.147, -0.289, 0.436));
+        let v = c.dot(&Vector3::new(0.615, -0.515, -0.100));
+        Color::new(y, u, v, self.a)
+    }
+
+    /// `XMColorYUVToRGB`, BT.601. Inverse of `rgb_to_yuv`.
+    pub fn yuv_to_rgb(&self) -> Self {
+        let c = self.to_vector3();
+        let r = c.dot(&Vector3::new(1.0, 0.0, 1.140));
+        let g = c.dot(&Vector3::new(1.0, -0.395, -0.581));
+        let b = c.dot(&Vector3::new(1.0, 2.032, 0.0));
+        Color::new(r, g, b, self.a)
+    }
+
+    /// `XMColorRGBToYUV_HD`, BT.709. Returns Y, U and V in r, g and b.
+    pub fn rgb_to_yuv_hd(&self) -> Self {
+        let c = self.to_vector3();
+        let y = c.dot(&Vector3::new(0.2126, 0.7152, 0.0722));
+        let u = c.dot(&Vector3::new(-0.0997, -0.3354, 0.4351));
+        let v = c.dot(&Vector3::new(0.6150, -0.5586, -0.0564));
+        Color::new(y, u, v, self.a)
+    }
+
+    /// `XMColorYUVToRGB_HD`, BT.709. Inverse of `rgb_to_yuv_hd`.
+    pub fn yuv_to_rgb_hd(&self) -> Self {
+        let c = self.to_vector3();
+        let r = c.dot(&Vector3::new(1.0, 0.0, 1.2803));
+        let g = c.dot(&Vector3::new(1.0, -0.2148, -0.3805));
+        let b = c.dot(&Vector3::new(1.0, 2.1279, 0.0));
+        Color::new(r, g, b, self.a)
+    }
+
+    /// `XMColorAdjustSaturation`. Interpolates between the luminance of the color (0.0) and the
+    /// color itself (1.0). Values above 1.0 increase the saturation.
+    pub fn adjust_saturation(&self, saturation: f32) -> Self {
+        let c = self.to_vector3();
+        let luminance = c.dot(&Vector3::new(0.2125, 0.7154, 0.0721));
+        self.map_rgb(|x| (x - luminance) * saturation + luminance)
+    }
+
+    /// `XMColorAdjustContrast`. Interpolates between 50% gray (0.0) and the color itself (1.0).
+    /// Values above 1.0 increase the contrast.
+    pub fn adjust_contrast(&self, contrast: f32) -> Self {
+        self.map_rgb(|x| (x - 0.5) * contrast + 0.5)
+    }
+
+    /// `XMColorNegative`
+    pub fn negative(&self) -> Self {
+        self.map_rgb(|x| 1.0 - x)
+    }
+
+    /// `XMColorModulate`. Multiplies every channel, including alpha.
+    pub fn modulate(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    /// Clamps every channel, including alpha, to [0, 1].
+    pub fn saturate(&self) -> Self {
+        let c = Vector4::from(*self).clamp(&Vector4::zero(), &Vector4::one());
+        Color::from(c)
+    }
+
+    fn to_vector3(self) -> Vector3 {
+        Vector3::new(self.r, self.g, self.b)
+    }
+}
+
+impl From<Vector4> for Color {
+    fn from(v: Vector4) -> Self {
+        Color::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Color> for Vector4 {
+    fn from(c: Color) -> Self {
+        Vector4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+impl From<packed::Color> for Color {
+    fn from(c: packed::Color) -> Self {
+        Color::from(Vector4::from(c))
+    }
+}
+
+impl From<Color> for packed::Color {
+    fn from(c: Color) -> Self {
+        packed::Color::from(Vector4::from(c))
+    }
+}
+
+impl Add for Color {
+    type Output = Self;
+    fn add(self, rhs: Color) -> Self::Output {
+        Color::from(Vector4::from(self) + Vector4::from(rhs))
+    }
+}
+
+impl Sub for Color {
+    type Output = Self;
+    fn sub(self, rhs: Color) -> Self::Output {
+        Color::from(Vector4::from(self) - Vector4::from(rhs))
+    }
+}
+
+impl Mul for Color {
+    type Output = Self;
+    fn mul(self, rhs: Color) -> Self::Output {
+        Color::from(Vector4::from(self) * Vector4::from(rhs))
+    }
+}
+
+impl Mul<f32> for Color {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self::Output {
+        Color::from(Vector4::from(self) * rhs)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support {
+    use super::Color;
+    use bytemuck::{Pod, Zeroable};
+
+    unsafe impl Zeroable for Color {}
+    unsafe impl Pod for Color {}
+}
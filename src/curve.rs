@@ -0,0 +1,378 @@
+//! Bezier, Hermite, Catmull-Rom and B-spline curves over any of `Vector2`, `Vector3` and
+//! `Vector4`.
+//!
+//! Every curve is parametrized by `t` in [0, 1]. The splines borrow their control points, and
+//! map equal steps of `t` to their segments, so the speed along them varies. `Curve` adds arc
+//! length parametrization and closest point queries on top of evaluation.
+
+use crate::math;
+use crate::vector::Vector;
+use core::ops::*;
+
+/// Vectors curves can be built from. Implemented for every `Vector` with the usual operators.
+pub trait ControlPoint:
+    Vector + Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+}
+
+impl<V> ControlPoint for V where
+    V: Vector + Copy + Add<Output = V> + Sub<Output = V> + Mul<f32, Output = V>
+{
+}
+
+/// 5 point Gauss-Legendre rule on [0, 1], as (node, weight).
+const GAUSS: [(f32, f32); 5] = [
+    (0.04691008, 0.11846344),
+    (0.23076534, 0.23931434),
+    (0.5, 0.28444445),
+    (0.76923466, 0.23931434),
+    (0.9530899, 0.11846344),
+];
+
+/// Gauss-Legendre steps per segment when integrating the arc length.
+const ARC_LENGTH_STEPS: usize = 4;
+/// Samples per segment searched before refining a closest point.
+const CLOSEST_POINT_SAMPLES: usize = 16;
+
+pub trait Curve<V: ControlPoint> {
+    /// Point at `t` in [0, 1].
+    fn eval(&self, t: f32) -> V;
+
+    /// Derivative with respect to `t`, the velocity along the curve.
+    fn derivative(&self, t: f32) -> V;
+
+    /// Number of polynomial pieces, evenly spread over [0, 1]. The derivatives may jump at their
+    /// joins, so integration and searches split there.
+    fn segments(&self) -> usize {
+        1
+    }
+
+    /// Length of the curve from 0 to `t`.
+    fn arc_length(&self, t: f32) -> f32 {
+        let steps = self.segments() * ARC_LENGTH_STEPS;
+        let end = t.clamp(0.0, 1.0);
+
+        let mut length = 0.0;
+        for i in 0..steps {
+            let a = i as f32 / steps as f32;
+            if a >= end {
+                break;
+            }
+            let b = ((i + 1) as f32 / steps as f32).min(end);
+            for (node, weight) in GAUSS {
+                length += weight * (b - a) * self.derivative(a + node * (b - a)).length();
+            }
+        }
+        length
+    }
+
+    fn length(&self) -> f32 {
+        self.arc_length(1.0)
+    }
+
+    /// `t` at which the arc length from 0 is `length`, clamped to the curve. Stepping `length`
+    /// evenly moves along the curve at constant speed.
+    fn t_at_arc_length(&self, length: f32) -> f32 {
+        let total = self.length();
+        if length <= 0.0 {
+            return 0.0;
+        }
+        if length >= total {
+            return 1.0;
+        }
+
+        // Newton's method, falling back to bisection when a step leaves the bracket
+        let (mut low, mut high) = (0.0, 1.0);
+        let mut t = length / total;
+        for _ in 0..32 {
+            let error = self.arc_length(t) - length;
+            if error.abs() < total * 1e-6 {
+                break;
+            }
+            if error > 0.0 {
+                high = t;
+            } else {
+                low = t;
+            }
+            let speed = self.derivative(t).length();
+            let next = t - error / speed;
+            t = if speed > 0.0 && next > low && next < high {
+                next
+            } else {
+                0.5 * (low + high)
+            };
+        }
+        t
+    }
+
+    /// `t` of the point on the curve closest to `p`. Searches samples along the curve, then
+    /// refines around the best one, so very tight curls may be missed.
+    fn closest_point(&self, p: &V) -> f32 {
+        let distance = |t: f32| (self.eval(t) - *p).length_sq();
+        let samples = self.segments() * CLOSEST_POINT_SAMPLES;
+        let step = 1.0 / samples as f32;
+
+        let mut best = 0.0;
+        let mut best_distance = distance(0.0);
+        for i in 1..=samples {
+            let t = i as f32 * step;
+            let d = distance(t);
+            if d < best_distance {
+                best = t;
+                best_distance = d;
+            }
+        }
+
+        // Golden section search in the neighboring intervals
+        const RATIO: f32 = 0.618034;
+        let (mut a, mut b) = ((best - step).max(0.0), (best + step).min(1.0));
+        let mut c = b - RATIO * (b - a);
+        let mut d = a + RATIO * (b - a);
+        let (mut fc, mut fd) = (distance(c), distance(d));
+        for _ in 0..40 {
+            if fc < fd {
+                (b, d, fd) = (d, c, fc);
+                c = b - RATIO * (b - a);
+                fc = distance(c);
+            } else {
+                (a, c, fc) = (c, d, fd);
+                d = a + RATIO * (b - a);
+                fd = distance(d);
+            }
+        }
+
+        let refined = 0.5 * (a + b);
+        if distance(refined) < best_distance {
+            refined
+        } else {
+            best
+        }
+    }
+}
+
+/// Splits `t` in [0, 1] into a segment index and the parameter within that segment.
+fn segment(t: f32, segments: usize) -> (usize, f32) {
+    let scaled = t.clamp(0.0, 1.0) * segments as f32;
+    let i = (math::floor(scaled) as usize).min(segments - 1);
+    (i, scaled - i as f32)
+}
+
+/// Bezier curve through `p0` and `p2`, pulled towards `p1`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct QuadraticBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+}
+
+impl<V: ControlPoint> QuadraticBezier<V> {
+    pub fn new(p0: V, p1: V, p2: V) -> Self {
+        QuadraticBezier { p0, p1, p2 }
+    }
+}
+
+impl<V: ControlPoint> Curve<V> for QuadraticBezier<V> {
+    fn eval(&self, t: f32) -> V {
+        let s = 1.0 - t;
+        self.p0 * (s * s) + self.p1 * (2.0 * s * t) + self.p2 * (t * t)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        (self.p1 - self.p0) * (2.0 * (1.0 - t)) + (self.p2 - self.p1) * (2.0 * t)
+    }
+}
+
+/// Bezier curve through `p0` and `p3`, leaving towards `p1` and arriving from `p2`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CubicBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V,
+}
+
+impl<V: ControlPoint> CubicBezier<V> {
+    pub fn new(p0: V, p1: V, p2: V, p3: V) -> Self {
+        CubicBezier { p0, p1, p2, p3 }
+    }
+}
+
+impl<V: ControlPoint> Curve<V> for CubicBezier<V> {
+    fn eval(&self, t: f32) -> V {
+        let s = 1.0 - t;
+        self.p0 * (s * s * s)
+            + self.p1 * (3.0 * s * s * t)
+            + self.p2 * (3.0 * s * t * t)
+            + self.p3 * (t * t * t)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        let s = 1.0 - t;
+        (self.p1 - self.p0) * (3.0 * s * s)
+            + (self.p2 - self.p1) * (6.0 * s * t)
+            + (self.p3 - self.p2) * (3.0 * t * t)
+    }
+}
+
+/// `XMVectorHermite`. Cubic from `p0` with tangent `t0` to `p1` with tangent `t1`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Hermite<V> {
+    pub p0: V,
+    pub t0: V,
+    pub p1: V,
+    pub t1: V,
+}
+
+impl<V: ControlPoint> Hermite<V> {
+    pub fn new(p0: V, t0: V, p1: V, t1: V) -> Self {
+        Hermite { p0, t0, p1, t1 }
+    }
+}
+
+impl<V: ControlPoint> Curve<V> for Hermite<V> {
+    fn eval(&self, t: f32) -> V {
+        let (t2, t3) = (t * t, t * t * t);
+        self.p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.t0 * (t3 - 2.0 * t2 + t)
+            + self.p1 * (-2.0 * t3 + 3.0 * t2)
+            + self.t1 * (t3 - t2)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        let t2 = t * t;
+        self.p0 * (6.0 * t2 - 6.0 * t)
+            + self.t0 * (3.0 * t2 - 4.0 * t + 1.0)
+            + self.p1 * (-6.0 * t2 + 6.0 * t)
+            + self.t1 * (3.0 * t2 - 2.0 * t)
+    }
+}
+
+/// Catmull-Rom spline through `points[1]` to `points[len - 2]`. The first and last points only
+/// shape the ends.
+///
+/// `alpha` spaces the knots by the distance between the points to that power. The uniform
+/// spline (0) is `XMVectorCatmullRom`. The centripetal spline (0.5) never forms cusps or loops
+/// within a segment, and needs distinct consecutive points.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CatmullRom<'a, V> {
+    points: &'a [V],
+    pub alpha: f32,
+}
+
+impl<'a, V: ControlPoint> CatmullRom<'a, V> {
+    /// Panics if there are fewer than 4 points.
+    pub fn new(points: &'a [V], alpha: f32) -> Self {
+        assert!(points.len() >= 4, "a Catmull-Rom spline needs 4 points");
+        CatmullRom { points, alpha }
+    }
+
+    pub fn uniform(points: &'a [V]) -> Self {
+        CatmullRom::new(points, 0.0)
+    }
+
+    pub fn centripetal(points: &'a [V]) -> Self {
+        CatmullRom::new(points, 0.5)
+    }
+
+    pub fn points(&self) -> &'a [V] {
+        self.points
+    }
+
+    /// Barry-Goldman pyramid for one segment, giving the point and the derivative with respect
+    /// to the segment parameter.
+    fn eval_segment(&self, t: f32) -> (V, V) {
+        let (i, u) = segment(t, self.segments());
+        let p = &self.points[i..i + 4];
+
+        let mut knots = [0.0; 4];
+        for j in 1..4 {
+            let distance = (p[j] - p[j - 1]).length();
+            let step = if self.alpha == 0.0 {
+                1.0
+            } else {
+                math::powf(distance, self.alpha)
+            };
+            knots[j] = knots[j - 1] + step;
+        }
+        let tau = knots[1] + u * (knots[2] - knots[1]);
+
+        // Interpolates between two (point, derivative) pairs over the knots [a, b]
+        let lerp = |(x, dx): (V, V), (y, dy): (V, V), a: f32, b: f32| -> (V, V) {
+            let (wx, wy) = ((b - tau) / (b - a), (tau - a) / (b - a));
+            (
+                x * wx + y * wy,
+                dx * wx + dy * wy + (y - x) * (1.0 / (b - a)),
+            )
+        };
+        let zero = V::zero();
+        let k = knots;
+        let a1 = lerp((p[0], zero), (p[1], zero), k[0], k[1]);
+        let a2 = lerp((p[1], zero), (p[2], zero), k[1], k[2]);
+        let a3 = lerp((p[2], zero), (p[3], zero), k[2], k[3]);
+        let b1 = lerp(a1, a2, k[0], k[2]);
+        let b2 = lerp(a2, a3, k[1], k[3]);
+        let (point, derivative) = lerp(b1, b2, k[1], k[2]);
+        (point, derivative * (knots[2] - knots[1]))
+    }
+}
+
+impl<V: ControlPoint> Curve<V> for CatmullRom<'_, V> {
+    fn eval(&self, t: f32) -> V {
+        self.eval_segment(t).0
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        self.eval_segment(t).1 * self.segments() as f32
+    }
+
+    fn segments(&self) -> usize {
+        self.points.len() - 3
+    }
+}
+
+/// Uniform cubic B-spline. Smoother than Catmull-Rom, with a continuous second derivative, but
+/// only approaches its control points.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct BSpline<'a, V> {
+    points: &'a [V],
+}
+
+impl<'a, V: ControlPoint> BSpline<'a, V> {
+    /// Panics if there are fewer than 4 points.
+    pub fn new(points: &'a [V]) -> Self {
+        assert!(points.len() >= 4, "a B-spline needs 4 points");
+        BSpline { points }
+    }
+
+    pub fn points(&self) -> &'a [V] {
+        self.points
+    }
+}
+
+impl<V: ControlPoint> Curve<V> for BSpline<'_, V> {
+    fn eval(&self, t: f32) -> V {
+        let (i, u) = segment(t, self.segments());
+        let p = &self.points[i..i + 4];
+        let (u2, u3, s) = (u * u, u * u * u, 1.0 - u);
+        (p[0] * (s * s * s)
+            + p[1] * (3.0 * u3 - 6.0 * u2 + 4.0)
+            + p[2] * (-3.0 * u3 + 3.0 * u2 + 3.0 * u + 1.0)
+            + p[3] * u3)
+            * (1.0 / 6.0)
+    }
+
+    fn derivative(&self, t: f32) -> V {
+        let (i, u) = segment(t, self.segments());
+        let p = &self.points[i..i + 4];
+        let (u2, s) = (u * u, 1.0 - u);
+        (p[0] * (-3.0 * s * s)
+            + p[1] * (9.0 * u2 - 12.0 * u)
+            + p[2] * (-9.0 * u2 + 6.0 * u + 3.0)
+            + p[3] * (3.0 * u2))
+            * (self.segments() as f32 / 6.0)
+    }
+
+    fn segments(&self) -> usize {
+        self.points.len() - 3
+    }
+}
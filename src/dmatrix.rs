@@ -0,0 +1,41 @@
+use crate::dvector::{DVector2, DVector3};
+use crate::math;
+use crate::matrix::{matrix_type, Matrix};
+use crate::vector::Vector;
+use core::ops::*;
+
+pub type DRow = [f64; 4];
+
+matrix_type! {
+    f64, DRow, DVector2, DVector3,
+    glam: DMat4,
+    glium: (DMatrixUniform, DoubleMat4),
+
+    /// Double precision counterpart of `Matrix`, laid out as 16 `f64`s.
+    #[repr(C)]
+    struct DMatrix;
+}
+
+/// Lossless conversion from single precision.
+impl From<Matrix> for DMatrix {
+    fn from(matrix: Matrix) -> Self {
+        let m = matrix.to_rows_array();
+        let row = |r: [f32; 4]| [r[0] as f64, r[1] as f64, r[2] as f64, r[3] as f64];
+        DMatrix {
+            m: [row(m[0]), row(m[1]), row(m[2]), row(m[3])],
+        }
+    }
+}
+
+impl DMatrix {
+    /// Rounds every element to the nearest `f32`.
+    pub fn to_f32(&self) -> Matrix {
+        let row = |r: DRow| [r[0] as f32, r[1] as f32, r[2] as f32, r[3] as f32];
+        Matrix::from_rows_array([
+            row(self.m[0]),
+            row(self.m[1]),
+            row(self.m[2]),
+            row(self.m[3]),
+        ])
+    }
+}
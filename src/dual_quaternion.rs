@@ -0,0 +1,200 @@
+use crate::math;
+use crate::matrix::Matrix;
+use crate::quaternion::{hamilton, Quaternion};
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3, Vector4};
+use core::ops::*;
+
+/// Rigid transformation, a rotation followed by a translation, as a unit dual quaternion.
+/// `real` is the rotation, `dual` is half the translation times the rotation.
+///
+/// `a * b` transforms by a, then by b, like `Matrix`. Blending dual quaternions does not shrink
+/// the mesh around twisted joints the way blending matrices does.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DualQuaternion {
+    pub real: Quaternion,
+    pub dual: Quaternion,
+}
+
+fn add(a: &Quaternion, b: &Quaternion) -> Quaternion {
+    Quaternion::from(Vector4::from(*a) + Vector4::from(*b))
+}
+
+fn scale(q: &Quaternion, s: f32) -> Quaternion {
+    Quaternion::from(Vector4::from(*q) * s)
+}
+
+fn pure(v: Vector3) -> Quaternion {
+    Quaternion::new(v.x, v.y, v.z, 0.0)
+}
+
+impl DualQuaternion {
+    pub const IDENTITY: Self =
+        DualQuaternion::new(Quaternion::IDENTITY, Quaternion::new(0.0, 0.0, 0.0, 0.0));
+
+    pub const fn new(real: Quaternion, dual: Quaternion) -> Self {
+        DualQuaternion { real, dual }
+    }
+
+    /// Rotates by `rotation`, which must be normalized, then translates by `translation`.
+    pub fn from_rotation_translation(rotation: Quaternion, translation: Vector3) -> Self {
+        let dual = scale(&hamilton(&pure(translation), &rotation), 0.5);
+        DualQuaternion::new(rotation, dual)
+    }
+
+    /// Rotation and translation of `m`, which must not contain a scale or a projection.
+    pub fn from_matrix(m: &Matrix) -> Self {
+        let rotation = Quaternion::from_rotation_matrix(m);
+        DualQuaternion::from_rotation_translation(rotation, Vector3::new(m[3][0], m[3][1], m[3][2]))
+    }
+
+    /// Rotation part. Same as `self.real`.
+    pub fn rotation(&self) -> Quaternion {
+        self.real
+    }
+
+    pub fn translation(&self) -> Vector3 {
+        let t = hamilton(&self.dual, &self.real.conjugate());
+        Vector3::new(t.x, t.y, t.z) * 2.0
+    }
+
+    /// Rotation matrix with the translation in the fourth row.
+    pub fn to_matrix(&self) -> Matrix {
+        let t = self.translation();
+        self.real.to_matrix() * Matrix::translation(t.x, t.y, t.z)
+    }
+
+    /// Scales to a unit real part, and makes the dual part orthogonal to it. Needed after
+    /// blending, and from time to time after many multiplications.
+    pub fn normalize(&self) -> Self {
+        let inv = 1.0 / self.real.length();
+        let real = scale(&self.real, inv);
+        let dual = scale(&self.dual, inv);
+        let dual = add(&dual, &scale(&real, -real.dot(&dual)));
+        DualQuaternion::new(real, dual)
+    }
+
+    /// The inverse transformation. `self` must be normalized.
+    pub fn conjugate(&self) -> Self {
+        DualQuaternion::new(self.real.conjugate(), self.dual.conjugate())
+    }
+
+    /// Screw linear interpolation. Moves at constant speed along the screw motion from `self` to
+    /// `other`, taking the shorter way around. Both must be normalized.
+    pub fn sclerp(&self, other: &Self, t: f32) -> Self {
+        let other = if self.real.dot(&other.real) < 0.0 {
+            -*other
+        } else {
+            *other
+        };
+        let conj = self.conjugate();
+        let diff = DualQuaternion::new(
+            hamilton(&conj.real, &other.real),
+            add(
+                &hamilton(&conj.real, &other.dual),
+                &hamilton(&conj.dual, &other.real),
+            ),
+        );
+        let step = diff.pow(t);
+        DualQuaternion::new(
+            hamilton(&self.real, &step.real),
+            add(
+                &hamilton(&self.real, &step.dual),
+                &hamilton(&self.dual, &step.real),
+            ),
+        )
+    }
+
+    /// Raises a unit dual quaternion with a non-negative scalar part to the power `t`, by scaling
+    /// the angle and the distance of its screw.
+    fn pow(&self, t: f32) -> Self {
+        let (r, e) = (&self.real, &self.dual);
+        let axis = Vector3::new(r.x, r.y, r.z);
+        let sin = axis.length();
+        if sin < 1e-6 {
+            let rotation = Quaternion::from((axis * t).extend(1.0)).normalize();
+            return DualQuaternion::from_rotation_translation(rotation, self.translation() * t);
+        }
+
+        let half = Scalar::atan2(sin, r.w);
+        let axis = axis / sin;
+        let pitch = -2.0 * e.w / sin;
+        let moment = (Vector3::new(e.x, e.y, e.z) - axis * (0.5 * pitch * r.w)) / sin;
+
+        let (sin, cos) = math::sin_cos(half * t);
+        let pitch = pitch * t;
+        let real = (axis * sin).extend(cos);
+        let dual = (moment * sin + axis * (0.5 * pitch * cos)).extend(-0.5 * pitch * sin);
+        DualQuaternion::new(Quaternion::from(real), Quaternion::from(dual))
+    }
+
+    /// Dual quaternion linear blending for skinning. Sums the bones weighted by `weights`, on the
+    /// same side of the hypersphere as the first bone, and normalizes. The weights do not need to
+    /// add up to 1, but must not all be 0.
+    ///
+    /// Panics if `bones` and `weights` have different lengths, or are empty.
+    pub fn blend(bones: &[Self], weights: &[f32]) -> Self {
+        assert_eq!(bones.len(), weights.len(), "one weight per bone");
+        let pivot = bones[0].real;
+
+        let mut real = Vector4::ZERO;
+        let mut dual = Vector4::ZERO;
+        for (bone, &weight) in bones.iter().zip(weights) {
+            let weight = if pivot.dot(&bone.real) < 0.0 {
+                -weight
+            } else {
+                weight
+            };
+            real += Vector4::from(bone.real) * weight;
+            dual += Vector4::from(bone.dual) * weight;
+        }
+        DualQuaternion::new(Quaternion::from(real), Quaternion::from(dual)).normalize()
+    }
+
+    /// Transforms a point. `self` must be normalized.
+    pub fn transform(&self, v: &Vector3) -> Vector3 {
+        self.real.rotate(v) + self.translation()
+    }
+
+    /// Transforms a direction, which only rotates it. `self` must be normalized.
+    pub fn transform_normal(&self, v: &Vector3) -> Vector3 {
+        self.real.rotate(v)
+    }
+}
+
+impl Mul for DualQuaternion {
+    type Output = DualQuaternion;
+    fn mul(self, rhs: DualQuaternion) -> DualQuaternion {
+        DualQuaternion::new(
+            hamilton(&rhs.real, &self.real),
+            add(
+                &hamilton(&rhs.real, &self.dual),
+                &hamilton(&rhs.dual, &self.real),
+            ),
+        )
+    }
+}
+
+impl Neg for DualQuaternion {
+    type Output = DualQuaternion;
+    /// Same transformation, the other way around the hypersphere.
+    fn neg(self) -> DualQuaternion {
+        DualQuaternion::new(-self.real, -self.dual)
+    }
+}
+
+impl From<DualQuaternion> for Matrix {
+    fn from(q: DualQuaternion) -> Self {
+        q.to_matrix()
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support {
+    use super::DualQuaternion;
+    use bytemuck::{Pod, Zeroable};
+
+    unsafe impl Zeroable for DualQuaternion {}
+    unsafe impl Pod for DualQuaternion {}
+}
@@ -0,0 +1,66 @@
+use crate::dmatrix::{DMatrix, DRow};
+use crate::math;
+use crate::vector::{vector_types, Vector, Vector2, Vector3, Vector4};
+use core::f64;
+use core::ops::*;
+
+vector_types! {
+    f64, DMatrix, DRow,
+    glam: (DVec2, DVec3, DVec4),
+    glium: (F64F64, F64F64F64, F64F64F64F64),
+
+    /// Double precision counterpart of `Vector2`, laid out as four `f64`s. Only x and y are
+    /// meaningful, the hidden z and w lanes are zero.
+    #[repr(C)]
+    struct DVector2;
+
+    /// Double precision counterpart of `Vector3`, laid out as four `f64`s. Only x, y and z are
+    /// meaningful, the hidden w lane is zero. Like in `Vector3`, it is padding, not a
+    /// homogeneous w.
+    #[repr(C)]
+    struct DVector3;
+
+    /// Double precision counterpart of `Vector4`, laid out as four `f64`s.
+    #[repr(C)]
+    struct DVector4;
+}
+
+//
+// Precision conversions
+//
+
+/// Lossless conversion from single precision.
+impl From<Vector2> for DVector2 {
+    fn from(v: Vector2) -> Self {
+        Self::new(v.x as f64, v.y as f64)
+    }
+}
+impl From<Vector3> for DVector3 {
+    fn from(v: Vector3) -> Self {
+        Self::new(v.x as f64, v.y as f64, v.z as f64)
+    }
+}
+impl From<Vector4> for DVector4 {
+    fn from(v: Vector4) -> Self {
+        Self::new(v.x as f64, v.y as f64, v.z as f64, v.w as f64)
+    }
+}
+
+impl DVector2 {
+    /// Rounds every component to the nearest `f32`.
+    pub fn to_f32(&self) -> Vector2 {
+        Vector2::new(self.x as f32, self.y as f32)
+    }
+}
+impl DVector3 {
+    /// Rounds every component to the nearest `f32`.
+    pub fn to_f32(&self) -> Vector3 {
+        Vector3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+}
+impl DVector4 {
+    /// Rounds every component to the nearest `f32`.
+    pub fn to_f32(&self) -> Vector4 {
+        Vector4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
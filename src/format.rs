@@ -0,0 +1,257 @@
+use crate::dmatrix::DMatrix;
+use crate::dvector::{DVector2, DVector3, DVector4};
+use crate::matrix::Matrix;
+use crate::matrix3::{Matrix3x3, Matrix3x4, Matrix4x3};
+use crate::vector::{Vector2, Vector3, Vector4};
+use core::fmt;
+use core::num::ParseFloatError;
+use core::str::FromStr;
+
+/// Error of the `FromStr` implementations of the vector and matrix types.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseError {
+    /// A component is not a valid number.
+    InvalidNumber(ParseFloatError),
+    /// The number of components does not match the type.
+    WrongCount { expected: usize, found: usize },
+    /// Brackets that are unbalanced, mismatched, nested too deep, or that do not enclose the
+    /// whole list or its rows.
+    Brackets,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber(e) => write!(f, "invalid component: {}", e),
+            ParseError::WrongCount { expected, found } => {
+                write!(f, "expected {} components, found {}", expected, found)
+            }
+            ParseError::Brackets => f.write_str("unbalanced or mismatched brackets"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidNumber(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parses `N` numbers separated by commas or whitespace, in `rows` rows. The whole list may be
+/// put in one group of `()` or `[]`, and when there are several rows, so may each row: sibling
+/// groups must be exactly the rows, and a group holds either numbers or groups.
+fn parse<S, const N: usize>(s: &str, rows: usize) -> Result<[S; N], ParseError>
+where
+    S: FromStr<Err = ParseFloatError> + Copy + Default,
+{
+    let row_len = N / rows;
+    let max_depth = if rows > 1 { 2 } else { 1 };
+    let mut values = [S::default(); N];
+    let mut found = 0;
+    let mut closers = [' '; 2];
+    let mut depth = 0;
+    // Per nesting level: groups and bare numbers directly inside the current group, and whether
+    // every group so far is a row
+    let mut groups = [0usize; 3];
+    let mut numbers = [0usize; 3];
+    let mut all_rows = [true; 3];
+    // Whether a comma has been seen since the last number or group, and whether one may come
+    let mut after_comma = false;
+    let mut comma_allowed = false;
+
+    let empty = || ParseError::InvalidNumber("".parse::<f32>().unwrap_err());
+    let check_groups = |groups: usize, all_rows: bool| {
+        if groups > 1 && (groups != rows || !all_rows) {
+            Err(ParseError::Brackets)
+        } else {
+            Ok(())
+        }
+    };
+
+    let mut start = 0;
+    for (i, c) in s.char_indices().chain(Some((s.len(), ' '))) {
+        let closer = match c {
+            '(' => Some(')'),
+            '[' => Some(']'),
+            _ => None,
+        };
+        if !(closer.is_some() || c == ')' || c == ']' || c == ',' || c.is_whitespace()) {
+            continue;
+        }
+
+        let token = &s[start..i];
+        start = i + c.len_utf8();
+        if !token.is_empty() {
+            if groups[depth] > 0 {
+                return Err(ParseError::Brackets);
+            }
+            let value = token.parse().map_err(ParseError::InvalidNumber)?;
+            if let Some(slot) = values.get_mut(found) {
+                *slot = value;
+            }
+            found += 1;
+            numbers[depth] += 1;
+            after_comma = false;
+            comma_allowed = true;
+        }
+
+        if let Some(closer) = closer {
+            if depth == max_depth || numbers[depth] > 0 {
+                return Err(ParseError::Brackets);
+            }
+            groups[depth] += 1;
+            closers[depth] = closer;
+            depth += 1;
+            groups[depth] = 0;
+            numbers[depth] = 0;
+            all_rows[depth] = true;
+            after_comma = false;
+            comma_allowed = false;
+        } else if c == ')' || c == ']' {
+            if depth == 0 || closers[depth - 1] != c {
+                return Err(ParseError::Brackets);
+            }
+            if after_comma {
+                return Err(empty());
+            }
+            check_groups(groups[depth], all_rows[depth])?;
+            if numbers[depth] != row_len {
+                all_rows[depth - 1] = false;
+            }
+            depth -= 1;
+            comma_allowed = true;
+        } else if c == ',' {
+            if !comma_allowed {
+                return Err(empty());
+            }
+            after_comma = true;
+            comma_allowed = false;
+        }
+    }
+
+    if depth != 0 {
+        return Err(ParseError::Brackets);
+    }
+    if after_comma {
+        return Err(empty());
+    }
+    check_groups(groups[0], all_rows[0])?;
+    if found != N {
+        return Err(ParseError::WrongCount { expected: N, found });
+    }
+    Ok(values)
+}
+
+/// Writes `values` between brackets, formatting each with the flags of `f`.
+fn write_list<S>(
+    f: &mut fmt::Formatter,
+    brackets: (&str, &str),
+    values: &[S],
+    write: fn(&S, &mut fmt::Formatter) -> fmt::Result,
+) -> fmt::Result {
+    f.write_str(brackets.0)?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write(value, f)?;
+    }
+    f.write_str(brackets.1)
+}
+
+macro_rules! vector_format {
+    ($ty:ident, $scalar:ty, $($field:ident),+) => {
+        /// Only the meaningful components, like `(1, 2, 3)`. Precision and width apply to each
+        /// component.
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_list(f, ("(", ")"), &[$(self.$field),+], fmt::Display::fmt)
+            }
+        }
+
+        impl fmt::LowerExp for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write_list(f, ("(", ")"), &[$(self.$field),+], fmt::LowerExp::fmt)
+            }
+        }
+
+        /// Accepts components separated by commas or whitespace, optionally in `()` or `[]`,
+        /// like `(1, 2, 3)` or `[1 2 3]`.
+        impl FromStr for $ty {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let [$($field),+] = parse::<$scalar, _>(s, 1)?;
+                Ok($ty::new($($field),+))
+            }
+        }
+    };
+}
+
+vector_format!(Vector2, f32, x, y);
+vector_format!(Vector3, f32, x, y, z);
+vector_format!(Vector4, f32, x, y, z, w);
+vector_format!(DVector2, f64, x, y);
+vector_format!(DVector3, f64, x, y, z);
+vector_format!(DVector4, f64, x, y, z, w);
+
+macro_rules! matrix_display {
+    ($ty:ident, $rows:expr) => {
+        /// One row per line, like `[1, 0, 0, 0]`. Precision and width apply to each element, so
+        /// a width lines up the columns.
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                for i in 0..$rows {
+                    if i > 0 {
+                        f.write_str("\n")?;
+                    }
+                    write_list(f, ("[", "]"), &self[i], fmt::Display::fmt)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl fmt::LowerExp for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                for i in 0..$rows {
+                    if i > 0 {
+                        f.write_str("\n")?;
+                    }
+                    write_list(f, ("[", "]"), &self[i], fmt::LowerExp::fmt)?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+macro_rules! matrix_format {
+    ($ty:ident, $scalar:ty, $rows:literal x $cols:literal) => {
+        matrix_display!($ty, $rows);
+
+        /// Accepts the elements in row-major order, separated by commas or whitespace. Rows and
+        /// the whole matrix may be put in `()` or `[]`, like `[[1 0 0 0] [0 1 0 0] ...]`. Parses
+        /// the output of `Display`.
+        impl FromStr for $ty {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let e = parse::<$scalar, { $rows * $cols }>(s, $rows)?;
+                let mut rows = [[0.0; $cols]; $rows];
+                for (i, row) in rows.iter_mut().enumerate() {
+                    row.copy_from_slice(&e[$cols * i..$cols * i + $cols]);
+                }
+                Ok($ty::from_rows_array(rows))
+            }
+        }
+    };
+}
+
+matrix_format!(Matrix, f32, 4 x 4);
+matrix_format!(DMatrix, f64, 4 x 4);
+matrix_format!(Matrix3x3, f32, 3 x 3);
+matrix_format!(Matrix4x3, f32, 4 x 3);
+matrix_format!(Matrix3x4, f32, 3 x 4);
@@ -0,0 +1,292 @@
+use crate::dvector::{DVector2, DVector3, DVector4};
+use crate::vector::{Vector, Vector2, Vector3, Vector4};
+use core::ops::*;
+
+/// How float components are turned into integers by `from_vector`.
+///
+/// Results outside of the integer range saturate, and NaN becomes zero.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    /// Nearest integer, half way cases away from zero. See `Vector::round`.
+    Round,
+    /// Toward negative infinity. See `Vector::floor`.
+    Floor,
+    /// Toward positive infinity. See `Vector::ceil`.
+    Ceil,
+    /// Toward zero. See `Vector::trunc`.
+    Trunc,
+}
+
+/// `XMINT2`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IVector2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// `XMINT3`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IVector3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// `XMINT4`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IVector4 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub w: i32,
+}
+
+/// `XMUINT2`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UVector2 {
+    pub x: u32,
+    pub y: u32,
+}
+
+/// `XMUINT3`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UVector3 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+}
+
+/// `XMUINT4`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UVector4 {
+    pub x: u32,
+    pub y: u32,
+    pub z: u32,
+    pub w: u32,
+}
+
+macro_rules! int_vector {
+    ($ty:ident, $int:ty, $vector:ident, $dvector:ident, $($field:ident),+) => {
+        impl $ty {
+            pub const fn new($($field: $int),+) -> Self {
+                $ty { $($field),+ }
+            }
+
+            pub const fn zero() -> Self {
+                Self::replicate(0)
+            }
+
+            pub const fn one() -> Self {
+                Self::replicate(1)
+            }
+
+            pub const fn replicate(value: $int) -> Self {
+                $ty { $($field: value),+ }
+            }
+
+            pub fn min(&self, other: &Self) -> Self {
+                Self::new($(self.$field.min(other.$field)),+)
+            }
+
+            pub fn max(&self, other: &Self) -> Self {
+                Self::new($(self.$field.max(other.$field)),+)
+            }
+
+            pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+                $(assert!(min.$field <= max.$field);)+
+                self.max(min).min(max)
+            }
+
+            /// `None` if any component overflows.
+            pub fn checked_add(&self, other: &Self) -> Option<Self> {
+                Some(Self::new($(self.$field.checked_add(other.$field)?),+))
+            }
+
+            /// `None` if any component overflows.
+            pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+                Some(Self::new($(self.$field.checked_sub(other.$field)?),+))
+            }
+
+            /// `None` if any component overflows.
+            pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+                Some(Self::new($(self.$field.checked_mul(other.$field)?),+))
+            }
+
+            /// `None` if any component of `other` is zero, or the division overflows.
+            pub fn checked_div(&self, other: &Self) -> Option<Self> {
+                Some(Self::new($(self.$field.checked_div(other.$field)?),+))
+            }
+
+            pub fn wrapping_add(&self, other: &Self) -> Self {
+                Self::new($(self.$field.wrapping_add(other.$field)),+)
+            }
+
+            pub fn wrapping_sub(&self, other: &Self) -> Self {
+                Self::new($(self.$field.wrapping_sub(other.$field)),+)
+            }
+
+            pub fn wrapping_mul(&self, other: &Self) -> Self {
+                Self::new($(self.$field.wrapping_mul(other.$field)),+)
+            }
+
+            pub fn from_vector(vector: &$vector, rounding: Rounding) -> Self {
+                let v = match rounding {
+                    Rounding::Round => vector.round(),
+                    Rounding::Floor => vector.floor(),
+                    Rounding::Ceil => vector.ceil(),
+                    Rounding::Trunc => vector.trunc(),
+                };
+                Self::new($(v.$field as $int),+)
+            }
+
+            /// Components beyond 2^24 are rounded to the nearest `f32`.
+            pub fn to_vector(&self) -> $vector {
+                $vector::new($(self.$field as f32),+)
+            }
+        }
+
+        /// Lossless conversion.
+        impl From<$ty> for $dvector {
+            fn from(v: $ty) -> Self {
+                $dvector::new($(v.$field as f64),+)
+            }
+        }
+
+        impl Add for $ty {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::new($(self.$field + rhs.$field),+)
+            }
+        }
+        impl AddAssign for $ty {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)+
+            }
+        }
+
+        impl Sub for $ty {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::new($(self.$field - rhs.$field),+)
+            }
+        }
+        impl SubAssign for $ty {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)+
+            }
+        }
+
+        impl Mul for $ty {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self::Output {
+                Self::new($(self.$field * rhs.$field),+)
+            }
+        }
+        impl MulAssign for $ty {
+            fn mul_assign(&mut self, rhs: Self) {
+                $(self.$field *= rhs.$field;)+
+            }
+        }
+        impl Mul<$int> for $ty {
+            type Output = Self;
+            fn mul(self, rhs: $int) -> Self::Output {
+                Self::new($(self.$field * rhs),+)
+            }
+        }
+        impl MulAssign<$int> for $ty {
+            fn mul_assign(&mut self, rhs: $int) {
+                $(self.$field *= rhs;)+
+            }
+        }
+        impl Mul<$ty> for $int {
+            type Output = $ty;
+            fn mul(self, rhs: $ty) -> Self::Output {
+                Self::Output::new($(self * rhs.$field),+)
+            }
+        }
+
+        impl Div for $ty {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self::Output {
+                Self::new($(self.$field / rhs.$field),+)
+            }
+        }
+        impl DivAssign for $ty {
+            fn div_assign(&mut self, rhs: Self) {
+                $(self.$field /= rhs.$field;)+
+            }
+        }
+        impl Div<$int> for $ty {
+            type Output = Self;
+            fn div(self, rhs: $int) -> Self::Output {
+                Self::new($(self.$field / rhs),+)
+            }
+        }
+        impl DivAssign<$int> for $ty {
+            fn div_assign(&mut self, rhs: $int) {
+                $(self.$field /= rhs;)+
+            }
+        }
+
+        impl Rem for $ty {
+            type Output = Self;
+            fn rem(self, rhs: Self) -> Self::Output {
+                Self::new($(self.$field % rhs.$field),+)
+            }
+        }
+        impl RemAssign for $ty {
+            fn rem_assign(&mut self, rhs: Self) {
+                $(self.$field %= rhs.$field;)+
+            }
+        }
+    };
+}
+
+int_vector!(IVector2, i32, Vector2, DVector2, x, y);
+int_vector!(IVector3, i32, Vector3, DVector3, x, y, z);
+int_vector!(IVector4, i32, Vector4, DVector4, x, y, z, w);
+int_vector!(UVector2, u32, Vector2, DVector2, x, y);
+int_vector!(UVector3, u32, Vector3, DVector3, x, y, z);
+int_vector!(UVector4, u32, Vector4, DVector4, x, y, z, w);
+
+impl Neg for IVector2 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+impl Neg for IVector3 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
+impl Neg for IVector4 {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support {
+    use super::*;
+    use bytemuck::{Pod, Zeroable};
+
+    macro_rules! pod {
+        ($($ty:ident),+) => {
+            $(
+                unsafe impl Zeroable for $ty {}
+                unsafe impl Pod for $ty {}
+            )+
+        };
+    }
+
+    pod!(IVector2, IVector3, IVector4, UVector2, UVector3, UVector4);
+}
@@ -1,7 +1,57 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("xmath needs either the `std` or the `libm` feature");
+
+pub mod batch;
+mod camera;
+mod color;
+pub mod curve;
+mod dmatrix;
+mod dual_quaternion;
+mod dvector;
+mod format;
+mod ivector;
+mod math;
 mod matrix;
+mod matrix3;
+pub mod packed;
+mod quaternion;
+pub mod sampling;
+pub mod scalar;
+pub mod sh;
+pub mod shadow;
+mod swizzle;
+mod transform;
 mod vector;
 
+pub use camera::Camera;
+pub use camera::OrbitCamera;
+pub use color::Color;
+pub use dmatrix::DMatrix;
+#[cfg(feature = "glium-support")]
+pub use dmatrix::DMatrixUniform;
+pub use dual_quaternion::DualQuaternion;
+pub use dvector::DVector2;
+pub use dvector::DVector3;
+pub use dvector::DVector4;
+pub use format::ParseError;
+pub use ivector::IVector2;
+pub use ivector::IVector3;
+pub use ivector::IVector4;
+pub use ivector::Rounding;
+pub use ivector::UVector2;
+pub use ivector::UVector3;
+pub use ivector::UVector4;
 pub use matrix::Matrix;
+#[cfg(feature = "glium-support")]
+pub use matrix::{MatrixUniform, UniformLayout};
+pub use matrix3::Matrix3x3;
+pub use matrix3::Matrix3x4;
+pub use matrix3::Matrix4x3;
+pub use quaternion::Quaternion;
+pub use scalar::Scalar;
+pub use transform::Transform;
 pub use vector::Vector;
 pub use vector::Vector2;
 pub use vector::Vector3;
@@ -0,0 +1,116 @@
+//! Float functions that need either `std` or `libm`.
+//!
+//! `core` does not provide `sqrt`, `round`, `sin` and friends, so everything goes through here
+//! instead of calling the inherent methods directly.
+
+pub trait Float: Copy {
+    fn sqrt(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn atan2(self, x: Self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn exp2(self) -> Self;
+    fn log2(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+}
+
+macro_rules! float {
+    ($ty:ident, $sincos:ident, $($f:ident($($arg:ident),*) => $libm:ident),+ $(,)?) => {
+        impl Float for $ty {
+            $(
+                fn $f(self $(, $arg: Self)*) -> Self {
+                    #[cfg(feature = "std")]
+                    {
+                        $ty::$f(self $(, $arg)*)
+                    }
+                    #[cfg(not(feature = "std"))]
+                    {
+                        libm::$libm(self $(, $arg)*)
+                    }
+                }
+            )+
+
+            fn sin_cos(self) -> (Self, Self) {
+                #[cfg(feature = "std")]
+                {
+                    $ty::sin_cos(self)
+                }
+                #[cfg(not(feature = "std"))]
+                {
+                    libm::$sincos(self)
+                }
+            }
+        }
+    };
+}
+
+float!(
+    f32, sincosf,
+    sqrt() => sqrtf,
+    round() => roundf,
+    trunc() => truncf,
+    floor() => floorf,
+    ceil() => ceilf,
+    powf(n) => powf,
+    sin() => sinf,
+    cos() => cosf,
+    tan() => tanf,
+    atan2(x) => atan2f,
+    exp() => expf,
+    ln() => logf,
+    exp2() => exp2f,
+    log2() => log2f,
+);
+float!(
+    f64, sincos,
+    sqrt() => sqrt,
+    round() => round,
+    trunc() => trunc,
+    floor() => floor,
+    ceil() => ceil,
+    powf(n) => pow,
+    sin() => sin,
+    cos() => cos,
+    tan() => tan,
+    atan2(x) => atan2,
+    exp() => exp,
+    ln() => log,
+    exp2() => exp2,
+    log2() => log2,
+);
+
+pub fn sqrt<F: Float>(x: F) -> F {
+    x.sqrt()
+}
+
+/// Half way cases away from zero.
+pub fn round<F: Float>(x: F) -> F {
+    x.round()
+}
+
+pub fn trunc<F: Float>(x: F) -> F {
+    x.trunc()
+}
+
+pub fn floor<F: Float>(x: F) -> F {
+    x.floor()
+}
+
+pub fn ceil<F: Float>(x: F) -> F {
+    x.ceil()
+}
+
+pub fn powf<F: Float>(x: F, n: F) -> F {
+    x.powf(n)
+}
+
+pub fn sin_cos<F: Float>(x: F) -> (F, F) {
+    x.sin_cos()
+}
@@ -1,282 +1,700 @@
-use crate::vector::{Vector, Vector3};
-use std::mem::zeroed;
-use std::ops::*;
+use crate::math;
+use crate::scalar;
+use crate::vector::{Vector, Vector2, Vector3};
+use core::ops::*;
 
 pub type Row = [f32; 4];
 
-#[derive(PartialEq, Clone, Debug)]
-pub struct Matrix {
-    m: [Row; 4],
-}
-
-impl Matrix {
-    pub fn zero() -> Self {
-        unsafe { zeroed() }
-    }
-
-    #[allow(clippy::too_many_arguments)]
-    #[rustfmt::skip]
-    pub fn new(
-        m00: f32, m01: f32, m02: f32, m03: f32,
-        m10: f32, m11: f32, m12: f32, m13: f32,
-        m20: f32, m21: f32, m22: f32, m23: f32,
-        m30: f32, m31: f32, m32: f32, m33: f32,
-    ) -> Self {
-        Matrix {
-            m: [
-                [m00, m01, m02, m03],
-                [m10, m11, m12, m13],
-                [m20, m21, m22, m23],
-                [m30, m31, m32, m33],
-            ]
+/// Defines a 4x4 matrix type with elements of type `$S`, its operators and the optional
+/// interoperability. Also used by `dmatrix` for `DMatrix`.
+macro_rules! matrix_type {
+    (
+        $S:ident, $Row:ident, $V2:ident, $V3:ident,
+        glam: $Glam:ident,
+        glium: ($Uniform:ident, $UniformValue:ident),
+        $(#[$attr:meta])* struct $M:ident;
+    ) => {
+        $(#[$attr])*
+        #[derive(PartialEq, Clone, Copy, Debug)]
+        pub struct $M {
+            m: [$Row; 4],
         }
-    }
 
-    pub fn identity() -> Self {
-        Matrix {
-            m: [
-                [1.0, 0.0, 0.0, 0.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [0.0, 0.0, 1.0, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
-            ],
+        impl $M {
+            pub const ZERO: Self = $M { m: [[0.0; 4]; 4] };
+            pub const IDENTITY: Self = $M {
+                m: [
+                    [1.0, 0.0, 0.0, 0.0],
+                    [0.0, 1.0, 0.0, 0.0],
+                    [0.0, 0.0, 1.0, 0.0],
+                    [0.0, 0.0, 0.0, 1.0],
+                ],
+            };
+
+            pub const fn zero() -> Self {
+                Self::ZERO
+            }
+
+            #[allow(clippy::too_many_arguments)]
+            #[rustfmt::skip]
+            pub const fn new(
+                m00: $S, m01: $S, m02: $S, m03: $S,
+                m10: $S, m11: $S, m12: $S, m13: $S,
+                m20: $S, m21: $S, m22: $S, m23: $S,
+                m30: $S, m31: $S, m32: $S, m33: $S,
+            ) -> Self {
+                $M {
+                    m: [
+                        [m00, m01, m02, m03],
+                        [m10, m11, m12, m13],
+                        [m20, m21, m22, m23],
+                        [m30, m31, m32, m33],
+                    ]
+                }
+            }
+
+            pub const fn identity() -> Self {
+                Self::IDENTITY
+            }
+
+            pub fn rotation_x(rad: $S) -> Self {
+                let (sin, cos) = math::sin_cos(rad);
+                Self::rotation_x_sin_cos(sin, cos)
+            }
+
+            pub fn rotation_y(rad: $S) -> Self {
+                let (sin, cos) = math::sin_cos(rad);
+                Self::rotation_y_sin_cos(sin, cos)
+            }
+
+            pub fn rotation_z(rad: $S) -> Self {
+                let (sin, cos) = math::sin_cos(rad);
+                Self::rotation_z_sin_cos(sin, cos)
+            }
+
+            pub fn orthographic(view_width: $S, view_height: $S, near_z: $S, far_z: $S) -> Self {
+                // assert(!XMScalarNearEqual(ViewWidth, 0.0f, 0.00001f));
+                // assert(!XMScalarNearEqual(ViewHeight, 0.0f, 0.00001f));
+                // assert(!XMScalarNearEqual(FarZ, NearZ, 0.00001f));
+                let f_range = 1.0 / (near_z - far_z);
+                $M {
+                    m: [
+                        [2.0 / view_width, 0.0, 0.0, 0.0],
+                        [0.0, 2.0 / view_height, 0.0, 0.0],
+                        [0.0, 0.0, f_range, 0.0],
+                        [0.0, 0.0, f_range * near_z, 1.0],
+                    ],
+                }
+            }
+
+            pub fn orthographic_off_center(
+                view_left: $S,
+                view_right: $S,
+                view_bottom: $S,
+                view_top: $S,
+                near_z: $S,
+                far_z: $S,
+            ) -> Self {
+                // reciprocal width and height
+                let r_width = 1.0 / (view_right - view_left);
+                let r_height = 1.0 / (view_top - view_bottom);
+                let range = 1.0 / (near_z - far_z);
+
+                $M {
+                    m: [
+                        [r_width + r_width, 0.0, 0.0, 0.0],
+                        [0.0, r_height + r_height, 0.0, 0.0],
+                        [0.0, 0.0, range, 0.0],
+                        [
+                            -(view_left + view_right) * r_width,
+                            -(view_top + view_bottom) * r_height,
+                            range * near_z,
+                            1.0,
+                        ],
+                    ],
+                }
+            }
+
+            pub fn look_at(eye: $V3, focus: $V3, up: $V3) -> Self {
+                Self::look_to(eye, focus - eye, up)
+            }
+
+            pub fn look_to(eye: $V3, dir: $V3, up: $V3) -> Self {
+                assert!(dir != $V3::zero());
+                assert!(!dir.is_infinite());
+                assert!(up != $V3::zero());
+                assert!(!up.is_infinite());
+
+                let neg_eye = -eye;
+                let neg_dir = -dir;
+
+                let r2 = neg_dir.normalize();
+                let r0 = up.cross(&r2).normalize();
+                let r1 = r2.cross(&r0);
+
+                let d0 = r0.dot(&neg_eye);
+                let d1 = r1.dot(&neg_eye);
+                let d2 = r2.dot(&neg_eye);
+
+                $M {
+                    m: [
+                        [r0.x, r1.x, r2.x, 0.0],
+                        [r0.y, r1.y, r2.y, 0.0],
+                        [r0.z, r1.z, r2.z, 0.0],
+                        [d0, d1, d2, 1.0],
+                    ],
+                }
+            }
+
+            pub fn perspective(width: $S, height: $S, near_z: $S, far_z: $S) -> Self {
+                let two_near_z = near_z + near_z;
+                let range = far_z / (near_z - far_z);
+
+                $M {
+                    m: [
+                        [two_near_z / width, 0.0, 0.0, 0.0],
+                        [0.0, two_near_z / height, 0.0, 0.0],
+                        [0.0, 0.0, range, -1.0],
+                        [0.0, 0.0, range * near_z, 0.0],
+                    ],
+                }
+            }
+
+            /// aspect: Width / Height
+            pub fn perspective_fov(fov: $S, aspect: $S, near_z: $S, far_z: $S) -> Self {
+                let (sin, cos) = math::sin_cos(0.5 * fov);
+                Self::perspective_fov_sin_cos(sin, cos, aspect, near_z, far_z)
+            }
+
+            pub const fn translation(ox: $S, oy: $S, oz: $S) -> Self {
+                $M {
+                    m: [
+                        [1.0, 0.0, 0.0, 0.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                        [0.0, 0.0, 1.0, 0.0],
+                        [ox, oy, oz, 1.0],
+                    ],
+                }
+            }
+
+            /// `XMMatrixScaling`
+            pub const fn scaling(sx: $S, sy: $S, sz: $S) -> Self {
+                $M {
+                    m: [
+                        [sx, 0.0, 0.0, 0.0],
+                        [0.0, sy, 0.0, 0.0],
+                        [0.0, 0.0, sz, 0.0],
+                        [0.0, 0.0, 0.0, 1.0],
+                    ],
+                }
+            }
+
+            /// `XMMatrixTransformation2D`. Scales by `scaling` along axes rotated by
+            /// `scaling_orientation` around `scaling_origin`, then rotates by `rotation` around
+            /// `rotation_origin`, then translates. Everything happens in the XY plane.
+            pub fn transformation_2d(
+                scaling_origin: $V2,
+                scaling_orientation: $S,
+                scaling: $V2,
+                rotation_origin: $V2,
+                rotation: $S,
+                translation: $V2,
+            ) -> Self {
+                let orientation = $M::rotation_z(scaling_orientation);
+
+                $M::translation(-scaling_origin.x, -scaling_origin.y, 0.0)
+                    * orientation.transpose()
+                    * $M::scaling(scaling.x, scaling.y, 1.0)
+                    * orientation
+                    * $M::translation(scaling_origin.x, scaling_origin.y, 0.0)
+                    * $M::affine_transformation_2d($V2::ONE, rotation_origin, rotation, translation)
+            }
+
+            /// `XMMatrixAffineTransformation2D`. Scales around the origin, then rotates by
+            /// `rotation` around `rotation_origin`, then translates. Everything happens in the XY
+            /// plane.
+            pub fn affine_transformation_2d(
+                scaling: $V2,
+                rotation_origin: $V2,
+                rotation: $S,
+                translation: $V2,
+            ) -> Self {
+                let (ox, oy) = (rotation_origin.x, rotation_origin.y);
+
+                $M::scaling(scaling.x, scaling.y, 1.0)
+                    * $M::translation(-ox, -oy, 0.0)
+                    * $M::rotation_z(rotation)
+                    * $M::translation(ox + translation.x, oy + translation.y, 0.0)
+            }
+
+            pub const fn from_rows_array(rows: [$Row; 4]) -> Self {
+                $M { m: rows }
+            }
+
+            pub fn from_cols_array(cols: [$Row; 4]) -> Self {
+                $M { m: cols }.transpose()
+            }
+
+            /// Row-major storage, `m[row][col]`. This is how DirectXMath stores `XMFLOAT4X4`.
+            pub const fn to_rows_array(&self) -> [$Row; 4] {
+                self.m
+            }
+
+            /// Column-major storage, `m[col][row]`. This is how GLSL reads a `mat4`.
+            pub fn to_cols_array(&self) -> [$Row; 4] {
+                self.transpose().m
+            }
+
+            pub fn transpose(self) -> Self {
+                $M {
+                    m: [
+                        [self.m[0][0], self.m[1][0], self.m[2][0], self.m[3][0]],
+                        [self.m[0][1], self.m[1][1], self.m[2][1], self.m[3][1]],
+                        [self.m[0][2], self.m[1][2], self.m[2][2], self.m[3][2]],
+                        [self.m[0][3], self.m[1][3], self.m[2][3], self.m[3][3]],
+                    ],
+                }
+            }
+
+            pub fn determinant(&self) -> $S {
+                let (s, c) = self.subfactors();
+                s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+            }
+
+            /// `XMMatrixInverse`, by cofactors. `None` when the matrix is singular.
+            pub fn inverse(&self) -> Option<Self> {
+                let det = self.determinant();
+                if det == 0.0 {
+                    return None;
+                }
+
+                let (s, c) = self.subfactors();
+                let m = &self.m;
+                let inv = 1.0 / det;
+                Some($M {
+                    m: [
+                        [
+                            (m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3]) * inv,
+                            (-m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3]) * inv,
+                            (m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3]) * inv,
+                            (-m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3]) * inv,
+                        ],
+                        [
+                            (-m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1]) * inv,
+                            (m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1]) * inv,
+                            (-m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1]) * inv,
+                            (m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1]) * inv,
+                        ],
+                        [
+                            (m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0]) * inv,
+                            (-m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0]) * inv,
+                            (m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0]) * inv,
+                            (-m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0]) * inv,
+                        ],
+                        [
+                            (-m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0]) * inv,
+                            (m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0]) * inv,
+                            (-m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0]) * inv,
+                            (m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0]) * inv,
+                        ],
+                    ],
+                })
+            }
         }
-    }
 
-    pub fn rotation_x(rad: f32) -> Self {
-        let (sin, cos) = rad.sin_cos();
+        impl $M {
+            // The bodies of the builders that take angles, from the sine and cosine. Shared with
+            // the `_est` builders, which only differ in how those are computed.
+            fn rotation_x_sin_cos(sin: $S, cos: $S) -> Self {
+                $M {
+                    m: [
+                        [1.0, 0.0, 0.0, 0.0],
+                        [0.0, cos, sin, 0.0],
+                        [0.0, -sin, cos, 0.0],
+                        [0.0, 0.0, 0.0, 1.0],
+                    ],
+                }
+            }
+
+            fn rotation_y_sin_cos(sin: $S, cos: $S) -> Self {
+                $M {
+                    m: [
+                        [cos, 0.0, -sin, 0.0],
+                        [0.0, 1.0, 0.0, 0.0],
+                        [sin, 0.0, cos, 0.0],
+                        [0.0, 0.0, 0.0, 1.0],
+                    ],
+                }
+            }
+
+            fn rotation_z_sin_cos(sin: $S, cos: $S) -> Self {
+                $M {
+                    m: [
+                        [cos, sin, 0.0, 0.0],
+                        [-sin, cos, 0.0, 0.0],
+                        [0.0, 0.0, 1.0, 0.0],
+                        [0.0, 0.0, 0.0, 1.0],
+                    ],
+                }
+            }
+
+            /// `sin` and `cos` are of half the field of view.
+            fn perspective_fov_sin_cos(
+                sin: $S,
+                cos: $S,
+                aspect: $S,
+                near_z: $S,
+                far_z: $S,
+            ) -> Self {
+                let f = cos / sin;
+                let range = far_z / (near_z - far_z);
+
+                $M {
+                    m: [
+                        [f / aspect, 0.0, 0.0, 0.0],
+                        [0.0, f, 0.0, 0.0],
+                        [0.0, 0.0, range, -1.0],
+                        [0.0, 0.0, range * near_z, 0.0],
+                    ],
+                }
+            }
+
+            /// 2x2 determinants of the top two rows and of the bottom two rows, shared by
+            /// `determinant` and `inverse`.
+            fn subfactors(&self) -> ([$S; 6], [$S; 6]) {
+                let m = &self.m;
+                let s = [
+                    m[0][0] * m[1][1] - m[1][0] * m[0][1],
+                    m[0][0] * m[1][2] - m[1][0] * m[0][2],
+                    m[0][0] * m[1][3] - m[1][0] * m[0][3],
+                    m[0][1] * m[1][2] - m[1][1] * m[0][2],
+                    m[0][1] * m[1][3] - m[1][1] * m[0][3],
+                    m[0][2] * m[1][3] - m[1][2] * m[0][3],
+                ];
+                let c = [
+                    m[2][0] * m[3][1] - m[3][0] * m[2][1],
+                    m[2][0] * m[3][2] - m[3][0] * m[2][2],
+                    m[2][0] * m[3][3] - m[3][0] * m[2][3],
+                    m[2][1] * m[3][2] - m[3][1] * m[2][2],
+                    m[2][1] * m[3][3] - m[3][1] * m[2][3],
+                    m[2][2] * m[3][3] - m[3][2] * m[2][3],
+                ];
+                (s, c)
+            }
+
+            fn multiply(&self, rhs: &$M) -> $M {
+                macro_rules! row {
+                    ($col:expr) => {{
+                        let x = self.m[$col][0];
+                        let y = self.m[$col][1];
+                        let z = self.m[$col][2];
+                        let w = self.m[$col][3];
+                        [
+                            (rhs.m[0][0] * x)
+                                + (rhs.m[1][0] * y)
+                                + (rhs.m[2][0] * z)
+                                + (rhs.m[3][0] * w),
+                            (rhs.m[0][1] * x)
+                                + (rhs.m[1][1] * y)
+                                + (rhs.m[2][1] * z)
+                                + (rhs.m[3][1] * w),
+                            (rhs.m[0][2] * x)
+                                + (rhs.m[1][2] * y)
+                                + (rhs.m[2][2] * z)
+                                + (rhs.m[3][2] * w),
+                            (rhs.m[0][3] * x)
+                                + (rhs.m[1][3] * y)
+                                + (rhs.m[2][3] * z)
+                                + (rhs.m[3][3] * w),
+                        ]
+                    }};
+                }
+
+                $M {
+                    m: [row!(0), row!(1), row!(2), row!(3)],
+                }
+            }
+        }
 
-        Matrix {
-            m: [
-                [1.0, 0.0, 0.0, 0.0],
-                [0.0, cos, sin, 0.0],
-                [0.0, -sin, cos, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
-            ],
+        impl Mul for $M {
+            type Output = $M;
+            fn mul(self, rhs: $M) -> $M {
+                self.multiply(&rhs)
+            }
         }
-    }
 
-    pub fn rotation_y(rad: f32) -> Self {
-        let (sin, cos) = rad.sin_cos();
+        impl Mul<$M> for &$M {
+            type Output = $M;
+            fn mul(self, rhs: $M) -> $M {
+                self.multiply(&rhs)
+            }
+        }
 
-        Matrix {
-            m: [
-                [cos, 0.0, -sin, 0.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [sin, 0.0, cos, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
-            ],
+        impl<'a> Mul<&'a $M> for $M {
+            type Output = $M;
+            fn mul(self, rhs: &'a $M) -> $M {
+                self.multiply(rhs)
+            }
         }
-    }
 
-    pub fn rotation_z(rad: f32) -> Self {
-        let (sin, cos) = rad.sin_cos();
+        impl<'a> Mul<&'a $M> for &$M {
+            type Output = $M;
+            fn mul(self, rhs: &'a $M) -> $M {
+                self.multiply(rhs)
+            }
+        }
 
-        Matrix {
-            m: [
-                [cos, sin, 0.0, 0.0],
-                [-sin, cos, 0.0, 0.0],
-                [0.0, 0.0, 1.0, 0.0],
-                [0.0, 0.0, 0.0, 1.0],
-            ],
+        impl Index<usize> for $M {
+            type Output = [$S; 4];
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.m[index]
+            }
         }
-    }
 
-    pub fn orthographic(view_width: f32, view_height: f32, near_z: f32, far_z: f32) -> Self {
-        // assert(!XMScalarNearEqual(ViewWidth, 0.0f, 0.00001f));
-        // assert(!XMScalarNearEqual(ViewHeight, 0.0f, 0.00001f));
-        // assert(!XMScalarNearEqual(FarZ, NearZ, 0.00001f));
-        let f_range = 1.0 / (near_z - far_z);
-        Matrix {
-            m: [
-                [2.0 / view_width, 0.0, 0.0, 0.0],
-                [0.0, 2.0 / view_height, 0.0, 0.0],
-                [0.0, 0.0, f_range, 0.0],
-                [0.0, 0.0, f_range * near_z, 1.0],
-            ],
+        /// glium_text support
+        ///
+        /// Returns rows, same as `to_rows_array`.
+        impl From<$M> for [[$S; 4]; 4] {
+            fn from(matrix: $M) -> Self {
+                matrix.m
+            }
         }
-    }
 
-    pub fn orthographic_off_center(
-        view_left: f32,
-        view_right: f32,
-        view_bottom: f32,
-        view_top: f32,
-        near_z: f32,
-        far_z: f32,
-    ) -> Self {
-        // reciprocal width and height
-        let r_width = 1.0 / (view_right - view_left);
-        let r_height = 1.0 / (view_top - view_bottom);
-        let range = 1.0 / (near_z - far_z);
-
-        Matrix {
-            m: [
-                [r_width + r_width, 0.0, 0.0, 0.0],
-                [0.0, r_height + r_height, 0.0, 0.0],
-                [0.0, 0.0, range, 0.0],
-                [
-                    -(view_left + view_right) * r_width,
-                    -(view_top + view_bottom) * r_height,
-                    range * near_z,
-                    1.0,
-                ],
-            ],
+        #[cfg(feature = "serde")]
+        mod serde_support {
+            use super::$M;
+            use core::fmt;
+            use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+            use serde::ser::SerializeTuple;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            /// 16 floats in row-major order.
+            impl Serialize for $M {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let mut tuple = serializer.serialize_tuple(16)?;
+                    for element in self.m.iter().flatten() {
+                        tuple.serialize_element(element)?;
+                    }
+                    tuple.end()
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $M {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    struct ElementsVisitor;
+
+                    impl<'de> Visitor<'de> for ElementsVisitor {
+                        type Value = $M;
+
+                        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            f.write_str("an array of 16 floats in row-major order")
+                        }
+
+                        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<$M, A::Error> {
+                            let mut m = [[0.0; 4]; 4];
+                            for (i, element) in m.iter_mut().flatten().enumerate() {
+                                *element = seq
+                                    .next_element()?
+                                    .ok_or_else(|| Error::invalid_length(i, &self))?;
+                            }
+                            if seq.next_element::<IgnoredAny>()?.is_some() {
+                                return Err(Error::invalid_length(17, &self));
+                            }
+                            Ok($M { m })
+                        }
+                    }
+
+                    deserializer.deserialize_tuple(16, ElementsVisitor)
+                }
+            }
         }
-    }
 
-    pub fn look_at(eye: Vector3, focus: Vector3, up: Vector3) -> Self {
-        Self::look_to(eye, focus - eye, up)
-    }
+        #[cfg(feature = "bytemuck")]
+        mod bytemuck_support {
+            use super::$M;
+            use bytemuck::{Pod, Zeroable};
 
-    pub fn look_to(eye: Vector3, dir: Vector3, up: Vector3) -> Self {
-        assert!(dir != Vector3::zero());
-        assert!(!dir.is_infinite());
-        assert!(up != Vector3::zero());
-        assert!(!up.is_infinite());
-
-        let neg_eye = -eye;
-        let neg_dir = -dir;
-
-        let r2 = neg_dir.normalize();
-        let r0 = up.cross(&r2).normalize();
-        let r1 = r2.cross(&r0);
-
-        let d0 = r0.dot(&neg_eye);
-        let d1 = r1.dot(&neg_eye);
-        let d2 = r2.dot(&neg_eye);
-
-        Matrix {
-            m: [
-                [r0.x, r1.x, r2.x, 0.0],
-                [r0.y, r1.y, r2.y, 0.0],
-                [r0.z, r1.z, r2.z, 0.0],
-                [d0, d1, d2, 1.0],
-            ],
+            unsafe impl Zeroable for $M {}
+            unsafe impl Pod for $M {}
         }
-    }
 
-    pub fn perspective(width: f32, height: f32, near_z: f32, far_z: f32) -> Self {
-        let two_near_z = near_z + near_z;
-        let range = far_z / (near_z - far_z);
-
-        Matrix {
-            m: [
-                [two_near_z / width, 0.0, 0.0, 0.0],
-                [0.0, two_near_z / height, 0.0, 0.0],
-                [0.0, 0.0, range, -1.0],
-                [0.0, 0.0, range * near_z, 0.0],
-            ],
+        // The other crates multiply column vectors, `matrix * vector`, while xmath multiplies
+        // row vectors, `vector * matrix`. Conversions keep the transformation the same, so the
+        // columns of their matrices are the rows of ours.
+
+        #[cfg(feature = "mint")]
+        mod mint_support {
+            use super::$M;
+
+            impl From<mint::ColumnMatrix4<$S>> for $M {
+                fn from(m: mint::ColumnMatrix4<$S>) -> Self {
+                    $M::from_rows_array(m.into())
+                }
+            }
+            impl From<$M> for mint::ColumnMatrix4<$S> {
+                fn from(m: $M) -> Self {
+                    m.to_rows_array().into()
+                }
+            }
+            impl mint::IntoMint for $M {
+                type MintType = mint::ColumnMatrix4<$S>;
+            }
+
+            impl From<mint::RowMatrix4<$S>> for $M {
+                fn from(m: mint::RowMatrix4<$S>) -> Self {
+                    $M::from_cols_array(m.into())
+                }
+            }
+            impl From<$M> for mint::RowMatrix4<$S> {
+                fn from(m: $M) -> Self {
+                    m.to_cols_array().into()
+                }
+            }
         }
-    }
 
-    /// aspect: Width / Height
-    pub fn perspective_fov(fov: f32, aspect: f32, near_z: f32, far_z: f32) -> Self {
-        let (sin, cos) = (0.5 * fov).sin_cos();
-        let f = cos / sin;
-        let range = far_z / (near_z - far_z);
-
-        Matrix {
-            m: [
-                [f / aspect, 0.0, 0.0, 0.0],
-                [0.0, f, 0.0, 0.0],
-                [0.0, 0.0, range, -1.0],
-                [0.0, 0.0, range * near_z, 0.0],
-            ],
+        #[cfg(feature = "glam")]
+        mod glam_support {
+            use super::$M;
+
+            impl From<glam::$Glam> for $M {
+                fn from(m: glam::$Glam) -> Self {
+                    $M::from_rows_array(m.to_cols_array_2d())
+                }
+            }
+            impl From<$M> for glam::$Glam {
+                fn from(m: $M) -> Self {
+                    glam::$Glam::from_cols_array_2d(&m.to_rows_array())
+                }
+            }
         }
-    }
 
-    pub fn translation(ox: f32, oy: f32, oz: f32) -> Self {
-        Matrix {
-            m: [
-                [1.0, 0.0, 0.0, 0.0],
-                [0.0, 1.0, 0.0, 0.0],
-                [0.0, 0.0, 1.0, 0.0],
-                [ox, oy, oz, 1.0],
-            ],
+        #[cfg(feature = "nalgebra")]
+        mod nalgebra_support {
+            use super::$M;
+
+            impl From<nalgebra::Matrix4<$S>> for $M {
+                fn from(m: nalgebra::Matrix4<$S>) -> Self {
+                    let mut rows = [[0.0; 4]; 4];
+                    for (i, row) in rows.iter_mut().enumerate() {
+                        for (j, element) in row.iter_mut().enumerate() {
+                            *element = m[(j, i)];
+                        }
+                    }
+                    $M::from_rows_array(rows)
+                }
+            }
+            impl From<$M> for nalgebra::Matrix4<$S> {
+                fn from(m: $M) -> Self {
+                    nalgebra::Matrix4::from_column_slice(m.to_rows_array().as_flattened())
+                }
+            }
         }
-    }
 
-    pub fn transpose(self) -> Self {
-        Matrix {
-            m: [
-                [self.m[0][0], self.m[1][0], self.m[2][0], self.m[3][0]],
-                [self.m[0][1], self.m[1][1], self.m[2][1], self.m[3][1]],
-                [self.m[0][2], self.m[1][2], self.m[2][2], self.m[3][2]],
-                [self.m[0][3], self.m[1][3], self.m[2][3], self.m[3][3]],
-            ],
+        #[cfg(feature = "cgmath")]
+        mod cgmath_support {
+            use super::$M;
+
+            impl From<cgmath::Matrix4<$S>> for $M {
+                fn from(m: cgmath::Matrix4<$S>) -> Self {
+                    $M::from_rows_array(m.into())
+                }
+            }
+            impl From<$M> for cgmath::Matrix4<$S> {
+                fn from(m: $M) -> Self {
+                    m.to_rows_array().into()
+                }
+            }
         }
-    }
-}
 
-impl Mul for Matrix {
-    type Output = Matrix;
-    fn mul(self, rhs: Matrix) -> Matrix {
-        &self * &rhs
-    }
+        #[cfg(feature = "glium-support")]
+        pub use self::glium_support::$Uniform;
+
+        #[cfg(feature = "glium-support")]
+        mod glium_support {
+            use super::$M;
+            use crate::matrix::UniformLayout;
+            use glium::uniforms::{AsUniformValue, UniformValue};
+
+            #[doc = concat!(
+                "A `",
+                stringify!($M),
+                "` converted to a uniform with an explicit `UniformLayout`."
+            )]
+            #[derive(Clone, Copy, PartialEq, Debug)]
+            pub struct $Uniform {
+                m: [[$S; 4]; 4],
+            }
+
+            impl $M {
+                pub fn uniform(&self, layout: UniformLayout) -> $Uniform {
+                    let m = match layout {
+                        UniformLayout::Rows => self.to_rows_array(),
+                        UniformLayout::Columns => self.to_cols_array(),
+                    };
+                    $Uniform { m }
+                }
+            }
+
+            impl AsUniformValue for $Uniform {
+                fn as_uniform_value(&self) -> UniformValue<'static> {
+                    UniformValue::$UniformValue(self.m)
+                }
+            }
+
+            /// Same as `UniformLayout::Rows`.
+            impl AsUniformValue for $M {
+                fn as_uniform_value(&self) -> UniformValue<'static> {
+                    UniformValue::$UniformValue(self.m)
+                }
+            }
+        }
+    };
 }
+pub(crate) use matrix_type;
 
-impl Mul<Matrix> for &Matrix {
-    type Output = Matrix;
-    fn mul(self, rhs: Matrix) -> Matrix {
-        self * &rhs
-    }
-}
+matrix_type! {
+    f32, Row, Vector2, Vector3,
+    glam: Mat4,
+    glium: (MatrixUniform, Mat4),
 
-impl<'a> Mul<&'a Matrix> for Matrix {
-    type Output = Matrix;
-    fn mul(self, rhs: &'a Matrix) -> Matrix {
-        &self * rhs
-    }
+    /// Row-major 4x4 matrix, laid out as 16 `f32`s and 16 byte aligned, like `XMMATRIX`.
+    #[repr(C, align(16))]
+    struct Matrix;
 }
 
-impl<'a> Mul<&'a Matrix> for &Matrix {
-    type Output = Matrix;
-
-    fn mul(self, rhs: &'a Matrix) -> Matrix {
-        macro_rules! row {
-            ($col:expr) => {{
-                let x = self.m[$col][0];
-                let y = self.m[$col][1];
-                let z = self.m[$col][2];
-                let w = self.m[$col][3];
-                [
-                    (rhs.m[0][0] * x) + (rhs.m[1][0] * y) + (rhs.m[2][0] * z) + (rhs.m[3][0] * w),
-                    (rhs.m[0][1] * x) + (rhs.m[1][1] * y) + (rhs.m[2][1] * z) + (rhs.m[3][1] * w),
-                    (rhs.m[0][2] * x) + (rhs.m[1][2] * y) + (rhs.m[2][2] * z) + (rhs.m[3][2] * w),
-                    (rhs.m[0][3] * x) + (rhs.m[1][3] * y) + (rhs.m[2][3] * z) + (rhs.m[3][3] * w),
-                ]
-            }};
-        }
+/// Builders with `scalar::sin_cos_est` instead of `sin_cos`, off by up to 1e-5 in exchange for
+/// speed.
+impl Matrix {
+    pub fn rotation_x_est(rad: f32) -> Self {
+        let (sin, cos) = scalar::sin_cos_est(rad);
+        Matrix::rotation_x_sin_cos(sin, cos)
+    }
 
-        Matrix {
-            m: [row!(0), row!(1), row!(2), row!(3)],
-        }
+    pub fn rotation_y_est(rad: f32) -> Self {
+        let (sin, cos) = scalar::sin_cos_est(rad);
+        Matrix::rotation_y_sin_cos(sin, cos)
     }
-}
 
-impl Index<usize> for Matrix {
-    type Output = [f32; 4];
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.m[index]
+    pub fn rotation_z_est(rad: f32) -> Self {
+        let (sin, cos) = scalar::sin_cos_est(rad);
+        Matrix::rotation_z_sin_cos(sin, cos)
     }
-}
 
-/// glium_text support
-impl From<Matrix> for [[f32; 4]; 4] {
-    fn from(matrix: Matrix) -> Self {
-        matrix.m
+    /// aspect: Width / Height
+    pub fn perspective_fov_est(fov: f32, aspect: f32, near_z: f32, far_z: f32) -> Self {
+        let (sin, cos) = scalar::sin_cos_est(0.5 * fov);
+        Matrix::perspective_fov_sin_cos(sin, cos, aspect, near_z, far_z)
     }
 }
 
 #[cfg(feature = "glium-support")]
-mod glium_support {
-    use super::Matrix;
-    use glium::uniforms::{AsUniformValue, UniformValue};
-
-    impl AsUniformValue for Matrix {
-        fn as_uniform_value(&self) -> UniformValue<'static> {
-            UniformValue::Mat4(self.m)
-        }
-    }
+/// How a `Matrix` is handed to GLSL, which reads a `mat4` column by column.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum UniformLayout {
+    /// Rows of the matrix become columns of the `mat4`, so the shader sees the transpose.
+    /// Multiply as `matrix * vector` in GLSL, which is `vector * matrix` in xmath.
+    Rows,
+    /// Columns of the matrix become columns of the `mat4`, so the shader sees the matrix as
+    /// is. Multiply as `vector * matrix` in GLSL, same as in xmath.
+    Columns,
 }
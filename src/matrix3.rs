@@ -0,0 +1,358 @@
+use crate::matrix::Matrix;
+use crate::vector::Vector3;
+use core::ops::*;
+
+/// Row-major 3x3 matrix, laid out as 9 `f32`s like `XMFLOAT3X3`. Rotations, scales and normal
+/// matrices, or 2D affine transforms.
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Matrix3x3 {
+    m: [[f32; 3]; 3],
+}
+
+/// Row-major 4x3 matrix, laid out as 12 `f32`s like `XMFLOAT4X3`. An affine transform without
+/// the constant last column of `Matrix`, the translation is in row 3.
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Matrix4x3 {
+    m: [[f32; 3]; 4],
+}
+
+/// Row-major 3x4 matrix, laid out as 12 `f32`s like `XMFLOAT3X4`. The transpose of
+/// `Matrix4x3`, so the translation is in column 3. This is the layout of the transform in
+/// `D3D12_RAYTRACING_INSTANCE_DESC`, and of a `float3x4` multiplied as `mul(m, v)` in HLSL.
+#[repr(C)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Matrix3x4 {
+    m: [[f32; 4]; 3],
+}
+
+impl Matrix3x3 {
+    pub const IDENTITY: Self = Matrix3x3 {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    #[allow(clippy::too_many_arguments)]
+    #[rustfmt::skip]
+    pub const fn new(
+        m00: f32, m01: f32, m02: f32,
+        m10: f32, m11: f32, m12: f32,
+        m20: f32, m21: f32, m22: f32,
+    ) -> Self {
+        Matrix3x3 {
+            m: [
+                [m00, m01, m02],
+                [m10, m11, m12],
+                [m20, m21, m22],
+            ]
+        }
+    }
+
+    pub const fn from_rows_array(rows: [[f32; 3]; 3]) -> Self {
+        Matrix3x3 { m: rows }
+    }
+
+    pub const fn to_rows_array(&self) -> [[f32; 3]; 3] {
+        self.m
+    }
+
+    pub fn transpose(self) -> Self {
+        let m = self.m;
+        Matrix3x3::new(
+            m[0][0], m[1][0], m[2][0], m[0][1], m[1][1], m[2][1], m[0][2], m[1][2], m[2][2],
+        )
+    }
+
+    /// `XMVector3Transform` with a 3x3 matrix, `v * self`.
+    pub fn transform(&self, v: &Vector3) -> Vector3 {
+        let m = &self.m;
+        Vector3::new(
+            v.x * m[0][0] + v.y * m[1][0] + v.z * m[2][0],
+            v.x * m[0][1] + v.y * m[1][1] + v.z * m[2][1],
+            v.x * m[0][2] + v.y * m[1][2] + v.z * m[2][2],
+        )
+    }
+
+    fn multiply(&self, rhs: &Matrix3x3) -> Matrix3x3 {
+        let row = |r: [f32; 3]| {
+            let v = Vector3::new(r[0], r[1], r[2]);
+            let v = rhs.transform(&v);
+            [v.x, v.y, v.z]
+        };
+        Matrix3x3 {
+            m: [row(self.m[0]), row(self.m[1]), row(self.m[2])],
+        }
+    }
+}
+
+impl Matrix4x3 {
+    pub const IDENTITY: Self = Matrix4x3 {
+        m: [
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0],
+        ],
+    };
+
+    #[allow(clippy::too_many_arguments)]
+    #[rustfmt::skip]
+    pub const fn new(
+        m00: f32, m01: f32, m02: f32,
+        m10: f32, m11: f32, m12: f32,
+        m20: f32, m21: f32, m22: f32,
+        m30: f32, m31: f32, m32: f32,
+    ) -> Self {
+        Matrix4x3 {
+            m: [
+                [m00, m01, m02],
+                [m10, m11, m12],
+                [m20, m21, m22],
+                [m30, m31, m32],
+            ]
+        }
+    }
+
+    pub const fn from_rows_array(rows: [[f32; 3]; 4]) -> Self {
+        Matrix4x3 { m: rows }
+    }
+
+    pub const fn to_rows_array(&self) -> [[f32; 3]; 4] {
+        self.m
+    }
+
+    /// `XMVector3Transform`, `v * self` with v as a point, so the translation applies.
+    pub fn transform(&self, v: &Vector3) -> Vector3 {
+        let t = self.m[3];
+        self.transform_normal(v) + Vector3::new(t[0], t[1], t[2])
+    }
+
+    /// `XMVector3TransformNormal`, `v * self` without the translation.
+    pub fn transform_normal(&self, v: &Vector3) -> Vector3 {
+        let m = &self.m;
+        Vector3::new(
+            v.x * m[0][0] + v.y * m[1][0] + v.z * m[2][0],
+            v.x * m[0][1] + v.y * m[1][1] + v.z * m[2][1],
+            v.x * m[0][2] + v.y * m[1][2] + v.z * m[2][2],
+        )
+    }
+
+    fn multiply(&self, rhs: &Matrix4x3) -> Matrix4x3 {
+        let normal = |r: [f32; 3]| {
+            let v = rhs.transform_normal(&Vector3::new(r[0], r[1], r[2]));
+            [v.x, v.y, v.z]
+        };
+        let t = self.m[3];
+        let t = rhs.transform(&Vector3::new(t[0], t[1], t[2]));
+        Matrix4x3 {
+            m: [
+                normal(self.m[0]),
+                normal(self.m[1]),
+                normal(self.m[2]),
+                [t.x, t.y, t.z],
+            ],
+        }
+    }
+}
+
+impl Matrix3x4 {
+    pub const IDENTITY: Self = Matrix3x4 {
+        m: [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ],
+    };
+
+    #[allow(clippy::too_many_arguments)]
+    #[rustfmt::skip]
+    pub const fn new(
+        m00: f32, m01: f32, m02: f32, m03: f32,
+        m10: f32, m11: f32, m12: f32, m13: f32,
+        m20: f32, m21: f32, m22: f32, m23: f32,
+    ) -> Self {
+        Matrix3x4 {
+            m: [
+                [m00, m01, m02, m03],
+                [m10, m11, m12, m13],
+                [m20, m21, m22, m23],
+            ]
+        }
+    }
+
+    pub const fn from_rows_array(rows: [[f32; 4]; 3]) -> Self {
+        Matrix3x4 { m: rows }
+    }
+
+    pub const fn to_rows_array(&self) -> [[f32; 4]; 3] {
+        self.m
+    }
+
+    /// Same as `Matrix4x3::transform`. Each row of `self` gives one component.
+    pub fn transform(&self, v: &Vector3) -> Vector3 {
+        let row = |r: [f32; 4]| v.x * r[0] + v.y * r[1] + v.z * r[2] + r[3];
+        Vector3::new(row(self.m[0]), row(self.m[1]), row(self.m[2]))
+    }
+
+    /// Same as `Matrix4x3::transform_normal`.
+    pub fn transform_normal(&self, v: &Vector3) -> Vector3 {
+        let row = |r: [f32; 4]| v.x * r[0] + v.y * r[1] + v.z * r[2];
+        Vector3::new(row(self.m[0]), row(self.m[1]), row(self.m[2]))
+    }
+
+    fn multiply(&self, rhs: &Matrix3x4) -> Matrix3x4 {
+        Matrix3x4::from(Matrix4x3::from(*self) * Matrix4x3::from(*rhs))
+    }
+}
+
+macro_rules! mul {
+    ($ty:ident) => {
+        /// Concatenation, like `Matrix`. `a * b` applies a first, then b.
+        impl Mul for $ty {
+            type Output = $ty;
+            fn mul(self, rhs: $ty) -> $ty {
+                self.multiply(&rhs)
+            }
+        }
+
+        impl Mul<$ty> for &$ty {
+            type Output = $ty;
+            fn mul(self, rhs: $ty) -> $ty {
+                self.multiply(&rhs)
+            }
+        }
+
+        impl<'a> Mul<&'a $ty> for $ty {
+            type Output = $ty;
+            fn mul(self, rhs: &'a $ty) -> $ty {
+                self.multiply(rhs)
+            }
+        }
+
+        impl<'a> Mul<&'a $ty> for &$ty {
+            type Output = $ty;
+            fn mul(self, rhs: &'a $ty) -> $ty {
+                self.multiply(rhs)
+            }
+        }
+    };
+}
+
+mul!(Matrix3x3);
+mul!(Matrix4x3);
+mul!(Matrix3x4);
+
+impl Index<usize> for Matrix3x3 {
+    type Output = [f32; 3];
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.m[index]
+    }
+}
+
+impl Index<usize> for Matrix4x3 {
+    type Output = [f32; 3];
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.m[index]
+    }
+}
+
+impl Index<usize> for Matrix3x4 {
+    type Output = [f32; 4];
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.m[index]
+    }
+}
+
+/// `XMLoadFloat3x3`. The rest is filled from the identity.
+impl From<Matrix3x3> for Matrix {
+    fn from(m: Matrix3x3) -> Self {
+        let [r0, r1, r2] = m.m;
+        Matrix::from_rows_array([
+            [r0[0], r0[1], r0[2], 0.0],
+            [r1[0], r1[1], r1[2], 0.0],
+            [r2[0], r2[1], r2[2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+}
+
+/// `XMStoreFloat3x3`. Keeps the upper left 3x3.
+impl From<Matrix> for Matrix3x3 {
+    fn from(m: Matrix) -> Self {
+        let row = |r: [f32; 4]| [r[0], r[1], r[2]];
+        Matrix3x3 {
+            m: [row(m[0]), row(m[1]), row(m[2])],
+        }
+    }
+}
+
+/// `XMLoadFloat4x3`. The last column becomes (0, 0, 0, 1).
+impl From<Matrix4x3> for Matrix {
+    fn from(m: Matrix4x3) -> Self {
+        let [r0, r1, r2, r3] = m.m;
+        Matrix::from_rows_array([
+            [r0[0], r0[1], r0[2], 0.0],
+            [r1[0], r1[1], r1[2], 0.0],
+            [r2[0], r2[1], r2[2], 0.0],
+            [r3[0], r3[1], r3[2], 1.0],
+        ])
+    }
+}
+
+/// `XMStoreFloat4x3`. Drops the last column, which is (0, 0, 0, 1) for affine transforms.
+impl From<Matrix> for Matrix4x3 {
+    fn from(m: Matrix) -> Self {
+        let row = |r: [f32; 4]| [r[0], r[1], r[2]];
+        Matrix4x3 {
+            m: [row(m[0]), row(m[1]), row(m[2]), row(m[3])],
+        }
+    }
+}
+
+/// `XMLoadFloat3x4`. Transposes, and the last column becomes (0, 0, 0, 1).
+impl From<Matrix3x4> for Matrix {
+    fn from(m: Matrix3x4) -> Self {
+        Matrix::from(Matrix4x3::from(m))
+    }
+}
+
+/// `XMStoreFloat3x4`. Transposes, and drops the last column, which is (0, 0, 0, 1) for affine
+/// transforms.
+impl From<Matrix> for Matrix3x4 {
+    fn from(m: Matrix) -> Self {
+        Matrix3x4::from(Matrix4x3::from(m))
+    }
+}
+
+/// Transpose.
+impl From<Matrix3x4> for Matrix4x3 {
+    fn from(m: Matrix3x4) -> Self {
+        let [r0, r1, r2] = m.m;
+        Matrix4x3::new(
+            r0[0], r1[0], r2[0], r0[1], r1[1], r2[1], r0[2], r1[2], r2[2], r0[3], r1[3], r2[3],
+        )
+    }
+}
+
+/// Transpose.
+impl From<Matrix4x3> for Matrix3x4 {
+    fn from(m: Matrix4x3) -> Self {
+        let [r0, r1, r2, r3] = m.m;
+        Matrix3x4::new(
+            r0[0], r1[0], r2[0], r3[0], r0[1], r1[1], r2[1], r3[1], r0[2], r1[2], r2[2], r3[2],
+        )
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support {
+    use super::{Matrix3x3, Matrix3x4, Matrix4x3};
+    use bytemuck::{Pod, Zeroable};
+
+    unsafe impl Zeroable for Matrix3x3 {}
+    unsafe impl Pod for Matrix3x3 {}
+    unsafe impl Zeroable for Matrix4x3 {}
+    unsafe impl Pod for Matrix4x3 {}
+    unsafe impl Zeroable for Matrix3x4 {}
+    unsafe impl Pod for Matrix3x4 {}
+}
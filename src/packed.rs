@@ -0,0 +1,658 @@
+//! Port of DirectXPackedVector, compact vertex and texel formats.
+//!
+//! Every type converts to and from the matching float vector with `From`. Normalized formats
+//! clamp out of range values, then round to the nearest representable value.
+
+use crate::math;
+use crate::vector::{Vector, Vector2, Vector3, Vector4};
+
+//
+// Float encoding shared by `Half` and `FloatR11G11B10`
+//
+
+/// Encodes a finite, non-negative `f32` as a float with a 5 bit exponent (bias 15) and
+/// `mantissa_bits` bits of mantissa, rounding to nearest even. Too large values become infinity.
+fn encode_small_float(abs: u32, mantissa_bits: u32) -> u32 {
+    let shift = 23 - mantissa_bits;
+    let exp = (abs >> 23) as i32 - 127 + 15;
+    let full = (abs & 0x007F_FFFF) | 0x0080_0000;
+
+    if abs < 0x0080_0000 {
+        // Zero and f32 subnormals are far below the smallest subnormal of the target.
+        return 0;
+    }
+    if exp >= 0x1F {
+        return 0x1F << mantissa_bits;
+    }
+
+    let (bits, rest, s) = if exp > 0 {
+        let bits = ((exp as u32) << mantissa_bits) | ((full >> shift) & ((1 << mantissa_bits) - 1));
+        (bits, full & ((1 << shift) - 1), shift)
+    } else {
+        let s = shift + 1 + (-exp) as u32;
+        if s > 25 {
+            return 0;
+        }
+        (full >> s, full & ((1 << s) - 1), s)
+    };
+
+    let half = 1 << (s - 1);
+    if rest > half || (rest == half && bits & 1 == 1) {
+        // Carries into the exponent, up to infinity, on their own.
+        bits + 1
+    } else {
+        bits
+    }
+}
+
+/// Inverse of `encode_small_float`. Exact, since every such value is representable in `f32`.
+fn decode_small_float(bits: u32, mantissa_bits: u32) -> f32 {
+    let exp = (bits >> mantissa_bits) & 0x1F;
+    let man = bits & ((1 << mantissa_bits) - 1);
+
+    match exp {
+        0x1F if man == 0 => f32::INFINITY,
+        0x1F => f32::NAN,
+        // 2^(-14 - mantissa_bits) per step of the mantissa
+        0 => man as f32 * f32::from_bits((127 - 14 - mantissa_bits) << 23),
+        _ => f32::from_bits(((exp + 127 - 15) << 23) | (man << (23 - mantissa_bits))),
+    }
+}
+
+fn pack_unorm(value: f32, scale: f32) -> f32 {
+    math::round(value.clamp(0.0, 1.0) * scale)
+}
+
+fn pack_snorm(value: f32, scale: f32) -> f32 {
+    math::round(value.clamp(-1.0, 1.0) * scale)
+}
+
+fn unpack_snorm(value: f32, scale: f32) -> f32 {
+    // The most negative integer is one step beyond -1.0
+    (value / scale).max(-1.0)
+}
+
+/// Sign extends the low `bits` bits of `value`.
+fn sign_extend(value: u32, bits: u32) -> i32 {
+    let shift = 32 - bits;
+    ((value << shift) as i32) >> shift
+}
+
+//
+// Half precision float
+//
+
+/// `HALF`, IEEE 754 binary16.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Half(u16);
+
+impl Half {
+    pub const fn from_bits(bits: u16) -> Self {
+        Half(bits)
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// `XMConvertFloatToHalf`. Rounds to nearest even, too large values become infinity.
+    pub fn from_f32(value: f32) -> Self {
+        let bits = value.to_bits();
+        let sign = (bits >> 16) & 0x8000;
+        let abs = bits & 0x7FFF_FFFF;
+
+        let half = if value.is_nan() {
+            // Keep the top of the payload, and make sure it stays a NaN
+            0x7E00 | ((abs >> 13) & 0x03FF)
+        } else {
+            encode_small_float(abs, 10)
+        };
+        Half((sign | half) as u16)
+    }
+
+    /// `XMConvertHalfToFloat`. Exact.
+    pub fn to_f32(self) -> f32 {
+        let value = decode_small_float(self.0 as u32 & 0x7FFF, 10);
+        if self.0 & 0x8000 != 0 {
+            -value
+        } else {
+            value
+        }
+    }
+}
+
+impl From<f32> for Half {
+    fn from(value: f32) -> Self {
+        Half::from_f32(value)
+    }
+}
+
+impl From<Half> for f32 {
+    fn from(value: Half) -> Self {
+        value.to_f32()
+    }
+}
+
+/// `XMHALF2`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct HalfVector2 {
+    pub x: Half,
+    pub y: Half,
+}
+
+/// `XMHALF4`
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct HalfVector4 {
+    pub x: Half,
+    pub y: Half,
+    pub z: Half,
+    pub w: Half,
+}
+
+impl From<Vector2> for HalfVector2 {
+    fn from(v: Vector2) -> Self {
+        HalfVector2 {
+            x: Half::from_f32(v.x),
+            y: Half::from_f32(v.y),
+        }
+    }
+}
+
+impl From<HalfVector2> for Vector2 {
+    fn from(v: HalfVector2) -> Self {
+        Vector2::new(v.x.to_f32(), v.y.to_f32())
+    }
+}
+
+impl From<Vector4> for HalfVector4 {
+    fn from(v: Vector4) -> Self {
+        HalfVector4 {
+            x: Half::from_f32(v.x),
+            y: Half::from_f32(v.y),
+            z: Half::from_f32(v.z),
+            w: Half::from_f32(v.w),
+        }
+    }
+}
+
+impl From<HalfVector4> for Vector4 {
+    fn from(v: HalfVector4) -> Self {
+        Vector4::new(v.x.to_f32(), v.y.to_f32(), v.z.to_f32(), v.w.to_f32())
+    }
+}
+
+//
+// 8 and 16 bit normalized integers
+//
+
+/// `XMBYTEN4`, signed normalized. Maps [-1, 1] to [-127, 127].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ByteN4 {
+    pub x: i8,
+    pub y: i8,
+    pub z: i8,
+    pub w: i8,
+}
+
+/// `XMUBYTEN4`, unsigned normalized. Maps [0, 1] to [0, 255].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UByteN4 {
+    pub x: u8,
+    pub y: u8,
+    pub z: u8,
+    pub w: u8,
+}
+
+/// `XMSHORTN2`, signed normalized. Maps [-1, 1] to [-32767, 32767].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ShortN2 {
+    pub x: i16,
+    pub y: i16,
+}
+
+/// `XMSHORTN4`, signed normalized. Maps [-1, 1] to [-32767, 32767].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ShortN4 {
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+    pub w: i16,
+}
+
+/// `XMUSHORTN2`, unsigned normalized. Maps [0, 1] to [0, 65535].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UShortN2 {
+    pub x: u16,
+    pub y: u16,
+}
+
+/// `XMUSHORTN4`, unsigned normalized. Maps [0, 1] to [0, 65535].
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UShortN4 {
+    pub x: u16,
+    pub y: u16,
+    pub z: u16,
+    pub w: u16,
+}
+
+impl From<Vector4> for ByteN4 {
+    fn from(v: Vector4) -> Self {
+        ByteN4 {
+            x: pack_snorm(v.x, 127.0) as i8,
+            y: pack_snorm(v.y, 127.0) as i8,
+            z: pack_snorm(v.z, 127.0) as i8,
+            w: pack_snorm(v.w, 127.0) as i8,
+        }
+    }
+}
+
+impl From<ByteN4> for Vector4 {
+    fn from(v: ByteN4) -> Self {
+        Vector4::new(
+            unpack_snorm(v.x as f32, 127.0),
+            unpack_snorm(v.y as f32, 127.0),
+            unpack_snorm(v.z as f32, 127.0),
+            unpack_snorm(v.w as f32, 127.0),
+        )
+    }
+}
+
+impl From<Vector4> for UByteN4 {
+    fn from(v: Vector4) -> Self {
+        UByteN4 {
+            x: pack_unorm(v.x, 255.0) as u8,
+            y: pack_unorm(v.y, 255.0) as u8,
+            z: pack_unorm(v.z, 255.0) as u8,
+            w: pack_unorm(v.w, 255.0) as u8,
+        }
+    }
+}
+
+impl From<UByteN4> for Vector4 {
+    fn from(v: UByteN4) -> Self {
+        Vector4::new(v.x as f32, v.y as f32, v.z as f32, v.w as f32) / 255.0
+    }
+}
+
+impl From<Vector2> for ShortN2 {
+    fn from(v: Vector2) -> Self {
+        ShortN2 {
+            x: pack_snorm(v.x, 32767.0) as i16,
+            y: pack_snorm(v.y, 32767.0) as i16,
+        }
+    }
+}
+
+impl From<ShortN2> for Vector2 {
+    fn from(v: ShortN2) -> Self {
+        Vector2::new(
+            unpack_snorm(v.x as f32, 32767.0),
+            unpack_snorm(v.y as f32, 32767.0),
+        )
+    }
+}
+
+impl From<Vector4> for ShortN4 {
+    fn from(v: Vector4) -> Self {
+        ShortN4 {
+            x: pack_snorm(v.x, 32767.0) as i16,
+            y: pack_snorm(v.y, 32767.0) as i16,
+            z: pack_snorm(v.z, 32767.0) as i16,
+            w: pack_snorm(v.w, 32767.0) as i16,
+        }
+    }
+}
+
+impl From<ShortN4> for Vector4 {
+    fn from(v: ShortN4) -> Self {
+        Vector4::new(
+            unpack_snorm(v.x as f32, 32767.0),
+            unpack_snorm(v.y as f32, 32767.0),
+            unpack_snorm(v.z as f32, 32767.0),
+            unpack_snorm(v.w as f32, 32767.0),
+        )
+    }
+}
+
+impl From<Vector2> for UShortN2 {
+    fn from(v: Vector2) -> Self {
+        UShortN2 {
+            x: pack_unorm(v.x, 65535.0) as u16,
+            y: pack_unorm(v.y, 65535.0) as u16,
+        }
+    }
+}
+
+impl From<UShortN2> for Vector2 {
+    fn from(v: UShortN2) -> Self {
+        Vector2::new(v.x as f32, v.y as f32) / 65535.0
+    }
+}
+
+impl From<Vector4> for UShortN4 {
+    fn from(v: Vector4) -> Self {
+        UShortN4 {
+            x: pack_unorm(v.x, 65535.0) as u16,
+            y: pack_unorm(v.y, 65535.0) as u16,
+            z: pack_unorm(v.z, 65535.0) as u16,
+            w: pack_unorm(v.w, 65535.0) as u16,
+        }
+    }
+}
+
+impl From<UShortN4> for Vector4 {
+    fn from(v: UShortN4) -> Self {
+        Vector4::new(v.x as f32, v.y as f32, v.z as f32, v.w as f32) / 65535.0
+    }
+}
+
+//
+// 10:10:10:2 formats
+//
+// x is stored in the lowest 10 bits, then y, z, and w in the highest 2 bits.
+//
+
+/// `XMDECN4`, signed normalized. x, y and z map [-1, 1] to [-511, 511], w maps to [-1, 1].
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DecN4 {
+    pub v: u32,
+}
+
+/// `XMXDECN4`, R10G10B10A2 with signed normalized x, y, z in [-511, 511] and an unsigned
+/// normalized w in [0, 3].
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct XDecN4 {
+    pub v: u32,
+}
+
+/// `XMUDECN4`, `DXGI_FORMAT_R10G10B10A2_UNORM`. Maps [0, 1] to [0, 1023] for x, y, z and to
+/// [0, 3] for w.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct UDecN4 {
+    pub v: u32,
+}
+
+fn pack_1010102(x: i32, y: i32, z: i32, w: i32) -> u32 {
+    (x as u32 & 0x3FF)
+        | ((y as u32 & 0x3FF) << 10)
+        | ((z as u32 & 0x3FF) << 20)
+        | ((w as u32) << 30)
+}
+
+impl From<Vector4> for DecN4 {
+    fn from(v: Vector4) -> Self {
+        let x = pack_snorm(v.x, 511.0) as i32;
+        let y = pack_snorm(v.y, 511.0) as i32;
+        let z = pack_snorm(v.z, 511.0) as i32;
+        let w = pack_snorm(v.w, 1.0) as i32;
+        DecN4 {
+            v: pack_1010102(x, y, z, w & 0x3),
+        }
+    }
+}
+
+impl From<DecN4> for Vector4 {
+    fn from(v: DecN4) -> Self {
+        Vector4::new(
+            unpack_snorm(sign_extend(v.v, 10) as f32, 511.0),
+            unpack_snorm(sign_extend(v.v >> 10, 10) as f32, 511.0),
+            unpack_snorm(sign_extend(v.v >> 20, 10) as f32, 511.0),
+            unpack_snorm(sign_extend(v.v >> 30, 2) as f32, 1.0),
+        )
+    }
+}
+
+impl From<Vector4> for XDecN4 {
+    fn from(v: Vector4) -> Self {
+        let x = pack_snorm(v.x, 511.0) as i32;
+        let y = pack_snorm(v.y, 511.0) as i32;
+        let z = pack_snorm(v.z, 511.0) as i32;
+        let w = pack_unorm(v.w, 3.0) as i32;
+        XDecN4 {
+            v: pack_1010102(x, y, z, w),
+        }
+    }
+}
+
+impl From<XDecN4> for Vector4 {
+    fn from(v: XDecN4) -> Self {
+        Vector4::new(
+            unpack_snorm(sign_extend(v.v, 10) as f32, 511.0),
+            unpack_snorm(sign_extend(v.v >> 10, 10) as f32, 511.0),
+            unpack_snorm(sign_extend(v.v >> 20, 10) as f32, 511.0),
+            (v.v >> 30) as f32 / 3.0,
+        )
+    }
+}
+
+impl From<Vector4> for UDecN4 {
+    fn from(v: Vector4) -> Self {
+        let x = pack_unorm(v.x, 1023.0) as i32;
+        let y = pack_unorm(v.y, 1023.0) as i32;
+        let z = pack_unorm(v.z, 1023.0) as i32;
+        let w = pack_unorm(v.w, 3.0) as i32;
+        UDecN4 {
+            v: pack_1010102(x, y, z, w),
+        }
+    }
+}
+
+impl From<UDecN4> for Vector4 {
+    fn from(v: UDecN4) -> Self {
+        Vector4::new(
+            (v.v & 0x3FF) as f32 / 1023.0,
+            ((v.v >> 10) & 0x3FF) as f32 / 1023.0,
+            ((v.v >> 20) & 0x3FF) as f32 / 1023.0,
+            (v.v >> 30) as f32 / 3.0,
+        )
+    }
+}
+
+//
+// Packed unsigned floats
+//
+
+/// `XMFLOAT3PK`, `DXGI_FORMAT_R11G11B10_FLOAT`.
+///
+/// x and y are unsigned floats with a 5 bit exponent and 6 bit mantissa, z has a 5 bit
+/// mantissa. Negative values become zero, too large values become infinity.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FloatR11G11B10 {
+    pub v: u32,
+}
+
+fn encode_unsigned_float(value: f32, mantissa_bits: u32) -> u32 {
+    if value.is_nan() {
+        (0x1F << mantissa_bits) | ((1 << mantissa_bits) - 1)
+    } else if value.is_sign_negative() {
+        0
+    } else {
+        encode_small_float(value.to_bits(), mantissa_bits)
+    }
+}
+
+impl From<Vector3> for FloatR11G11B10 {
+    fn from(v: Vector3) -> Self {
+        let x = encode_unsigned_float(v.x, 6);
+        let y = encode_unsigned_float(v.y, 6);
+        let z = encode_unsigned_float(v.z, 5);
+        FloatR11G11B10 {
+            v: x | (y << 11) | (z << 22),
+        }
+    }
+}
+
+impl From<FloatR11G11B10> for Vector3 {
+    fn from(v: FloatR11G11B10) -> Self {
+        Vector3::new(
+            decode_small_float(v.v & 0x7FF, 6),
+            decode_small_float((v.v >> 11) & 0x7FF, 6),
+            decode_small_float(v.v >> 22, 5),
+        )
+    }
+}
+
+//
+// Colors
+//
+
+/// `XMCOLOR`, `DXGI_FORMAT_B8G8R8A8_UNORM`. Converts to and from a `Vector4` holding
+/// (r, g, b, a).
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Color {
+    pub b: u8,
+    pub g: u8,
+    pub r: u8,
+    pub a: u8,
+}
+
+impl Color {
+    /// From `0xAARRGGBB`.
+    pub const fn from_argb(argb: u32) -> Self {
+        Color {
+            b: argb as u8,
+            g: (argb >> 8) as u8,
+            r: (argb >> 16) as u8,
+            a: (argb >> 24) as u8,
+        }
+    }
+
+    /// To `0xAARRGGBB`.
+    pub const fn to_argb(self) -> u32 {
+        (self.b as u32) | (self.g as u32) << 8 | (self.r as u32) << 16 | (self.a as u32) << 24
+    }
+}
+
+impl From<Vector4> for Color {
+    fn from(v: Vector4) -> Self {
+        let v = (v.clamp(&Vector4::zero(), &Vector4::one()) * 255.0).round();
+        Color {
+            b: v.z as u8,
+            g: v.y as u8,
+            r: v.x as u8,
+            a: v.w as u8,
+        }
+    }
+}
+
+impl From<Color> for Vector4 {
+    fn from(c: Color) -> Self {
+        Vector4::new(c.r as f32, c.g as f32, c.b as f32, c.a as f32) / 255.0
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support {
+    use super::*;
+    use bytemuck::{Pod, Zeroable};
+
+    macro_rules! pod {
+        ($($ty:ident),+) => {
+            $(
+                unsafe impl Zeroable for $ty {}
+                unsafe impl Pod for $ty {}
+            )+
+        };
+    }
+
+    pod!(
+        Half,
+        HalfVector2,
+        HalfVector4,
+        ByteN4,
+        UByteN4,
+        ShortN2,
+        ShortN4,
+        UShortN2,
+        UShortN4,
+        DecN4,
+        XDecN4,
+        UDecN4,
+        FloatR11G11B10,
+        Color
+    );
+}
+
+/// The normalized formats need `normalize(true)` in `implement_vertex!` to read as floats in
+/// the shader. `XDecN4` has no OpenGL equivalent, and OpenGL only reads BGRA8 colors through
+/// `GL_BGRA`, which glium doesn't expose, so neither implements `Attribute`.
+#[cfg(feature = "glium-support")]
+mod glium_support {
+    use super::*;
+    use glium::vertex::{Attribute, AttributeType};
+
+    unsafe impl Attribute for Half {
+        fn get_type() -> AttributeType {
+            AttributeType::F16
+        }
+    }
+    unsafe impl Attribute for HalfVector2 {
+        fn get_type() -> AttributeType {
+            AttributeType::F16F16
+        }
+    }
+    unsafe impl Attribute for HalfVector4 {
+        fn get_type() -> AttributeType {
+            AttributeType::F16F16F16F16
+        }
+    }
+    unsafe impl Attribute for ByteN4 {
+        fn get_type() -> AttributeType {
+            AttributeType::I8I8I8I8
+        }
+    }
+    unsafe impl Attribute for UByteN4 {
+        fn get_type() -> AttributeType {
+            AttributeType::U8U8U8U8
+        }
+    }
+    unsafe impl Attribute for ShortN2 {
+        fn get_type() -> AttributeType {
+            AttributeType::I16I16
+        }
+    }
+    unsafe impl Attribute for ShortN4 {
+        fn get_type() -> AttributeType {
+            AttributeType::I16I16I16I16
+        }
+    }
+    unsafe impl Attribute for UShortN2 {
+        fn get_type() -> AttributeType {
+            AttributeType::U16U16
+        }
+    }
+    unsafe impl Attribute for UShortN4 {
+        fn get_type() -> AttributeType {
+            AttributeType::U16U16U16U16
+        }
+    }
+    unsafe impl Attribute for DecN4 {
+        fn get_type() -> AttributeType {
+            AttributeType::I2I10I10I10Reversed
+        }
+    }
+    unsafe impl Attribute for UDecN4 {
+        fn get_type() -> AttributeType {
+            AttributeType::U2U10U10U10Reversed
+        }
+    }
+    unsafe impl Attribute for FloatR11G11B10 {
+        fn get_type() -> AttributeType {
+            AttributeType::F10F11F11UnsignedIntReversed
+        }
+    }
+}
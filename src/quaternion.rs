@@ -0,0 +1,267 @@
+use crate::math;
+use crate::matrix::Matrix;
+use crate::scalar::Scalar;
+use crate::vector::{Vector, Vector3, Vector4};
+use core::ops::*;
+
+/// Rotation quaternion, laid out as four `f32`s and 16 byte aligned like `XMVECTOR`. x, y and z
+/// are the vector part, w is the scalar part.
+///
+/// `a * b` rotates by a, then by b, like `XMQuaternionMultiply` and like `Matrix`.
+#[repr(C, align(16))]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Quaternion {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+/// Hamilton product, `a b`. Rotates by b, then by a.
+pub(crate) fn hamilton(a: &Quaternion, b: &Quaternion) -> Quaternion {
+    Quaternion::new(
+        a.w * b.x + a.x * b.w + a.y * b.z - a.z * b.y,
+        a.w * b.y - a.x * b.z + a.y * b.w + a.z * b.x,
+        a.w * b.z + a.x * b.y - a.y * b.x + a.z * b.w,
+        a.w * b.w - a.x * b.x - a.y * b.y - a.z * b.z,
+    )
+}
+
+impl Quaternion {
+    pub const IDENTITY: Self = Quaternion::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Quaternion { x, y, z, w }
+    }
+
+    /// `XMQuaternionRotationAxis`. Rotation by `angle` radians around `axis`, which does not
+    /// need to be normalized. Same direction as `Matrix::rotation_x` and friends.
+    pub fn from_axis_angle(axis: Vector3, angle: f32) -> Self {
+        let (sin, cos) = math::sin_cos(0.5 * angle);
+        let v = axis.normalize() * sin;
+        Quaternion::new(v.x, v.y, v.z, cos)
+    }
+
+    /// `XMQuaternionRotationMatrix`. Rotation part of `m`, which must not contain a scale.
+    pub fn from_rotation_matrix(m: &Matrix) -> Self {
+        let (m00, m11, m22) = (m[0][0], m[1][1], m[2][2]);
+        let trace = m00 + m11 + m22;
+
+        let q = if trace > 0.0 {
+            let s = 2.0 * math::sqrt(trace + 1.0);
+            Quaternion::new(
+                (m[1][2] - m[2][1]) / s,
+                (m[2][0] - m[0][2]) / s,
+                (m[0][1] - m[1][0]) / s,
+                0.25 * s,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = 2.0 * math::sqrt(1.0 + m00 - m11 - m22);
+            Quaternion::new(
+                0.25 * s,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] - m[2][1]) / s,
+            )
+        } else if m11 > m22 {
+            let s = 2.0 * math::sqrt(1.0 + m11 - m00 - m22);
+            Quaternion::new(
+                (m[0][1] + m[1][0]) / s,
+                0.25 * s,
+                (m[1][2] + m[2][1]) / s,
+                (m[2][0] - m[0][2]) / s,
+            )
+        } else {
+            let s = 2.0 * math::sqrt(1.0 + m22 - m00 - m11);
+            Quaternion::new(
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                0.25 * s,
+                (m[0][1] - m[1][0]) / s,
+            )
+        };
+        q.normalize()
+    }
+
+    /// `XMMatrixRotationQuaternion`. `self` must be normalized.
+    pub fn to_matrix(&self) -> Matrix {
+        let Quaternion { x, y, z, w } = *self;
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        Matrix::from_rows_array([
+            [1.0 - 2.0 * (yy + zz), 2.0 * (xy + wz), 2.0 * (xz - wy), 0.0],
+            [2.0 * (xy - wz), 1.0 - 2.0 * (xx + zz), 2.0 * (yz + wx), 0.0],
+            [2.0 * (xz + wy), 2.0 * (yz - wx), 1.0 - 2.0 * (xx + yy), 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// `XMQuaternionDot`
+    pub fn dot(&self, other: &Self) -> f32 {
+        Vector4::from(*self).dot(&Vector4::from(*other))
+    }
+
+    /// `XMQuaternionLength`
+    pub fn length(&self) -> f32 {
+        Vector4::from(*self).length()
+    }
+
+    /// `XMQuaternionNormalize`
+    pub fn normalize(&self) -> Self {
+        Quaternion::from(Vector4::from(*self).normalize())
+    }
+
+    /// `XMQuaternionConjugate`. The inverse rotation, if `self` is normalized.
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// `XMQuaternionInverse`
+    pub fn inverse(&self) -> Self {
+        let c = Vector4::from(self.conjugate());
+        Quaternion::from(c / self.dot(self))
+    }
+
+    /// `XMQuaternionSlerp`. Takes the shorter way around, and falls back to a normalized lerp
+    /// when the rotations are almost the same.
+    pub fn slerp(&self, other: &Self, t: f32) -> Self {
+        let a = Vector4::from(*self);
+        let mut b = Vector4::from(*other);
+        let mut cos = a.dot(&b);
+        if cos < 0.0 {
+            b = -b;
+            cos = -cos;
+        }
+
+        let (wa, wb) = if cos > 0.9999 {
+            (1.0 - t, t)
+        } else {
+            let sin = math::sqrt(1.0 - cos * cos);
+            let angle = Scalar::atan2(sin, cos);
+            (
+                Scalar::sin((1.0 - t) * angle) / sin,
+                Scalar::sin(t * angle) / sin,
+            )
+        };
+        Quaternion::from(a * wa + b * wb).normalize()
+    }
+
+    /// `XMVector3Rotate`. `self` must be normalized.
+    pub fn rotate(&self, v: &Vector3) -> Vector3 {
+        let q = Vector3::new(self.x, self.y, self.z);
+        let t = q.cross(v) * 2.0;
+        *v + t * self.w + q.cross(&t)
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        hamilton(&rhs, &self)
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+    /// Same rotation, the other way around the hypersphere.
+    fn neg(self) -> Quaternion {
+        Quaternion::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl From<Vector4> for Quaternion {
+    fn from(v: Vector4) -> Self {
+        Quaternion::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Quaternion> for Vector4 {
+    fn from(q: Quaternion) -> Self {
+        Vector4::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_support {
+    use super::Quaternion;
+    use bytemuck::{Pod, Zeroable};
+
+    unsafe impl Zeroable for Quaternion {}
+    unsafe impl Pod for Quaternion {}
+}
+
+#[cfg(feature = "mint")]
+mod mint_support {
+    use super::Quaternion;
+
+    impl From<mint::Quaternion<f32>> for Quaternion {
+        fn from(q: mint::Quaternion<f32>) -> Self {
+            Quaternion::new(q.v.x, q.v.y, q.v.z, q.s)
+        }
+    }
+    impl From<Quaternion> for mint::Quaternion<f32> {
+        fn from(q: Quaternion) -> Self {
+            mint::Quaternion {
+                v: mint::Vector3 {
+                    x: q.x,
+                    y: q.y,
+                    z: q.z,
+                },
+                s: q.w,
+            }
+        }
+    }
+    impl mint::IntoMint for Quaternion {
+        type MintType = mint::Quaternion<f32>;
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_support {
+    use super::Quaternion;
+
+    impl From<glam::Quat> for Quaternion {
+        fn from(q: glam::Quat) -> Self {
+            Quaternion::new(q.x, q.y, q.z, q.w)
+        }
+    }
+    impl From<Quaternion> for glam::Quat {
+        fn from(q: Quaternion) -> Self {
+            glam::Quat::from_xyzw(q.x, q.y, q.z, q.w)
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_support {
+    use super::Quaternion;
+
+    impl From<nalgebra::Quaternion<f32>> for Quaternion {
+        fn from(q: nalgebra::Quaternion<f32>) -> Self {
+            Quaternion::new(q.i, q.j, q.k, q.w)
+        }
+    }
+    impl From<Quaternion> for nalgebra::Quaternion<f32> {
+        fn from(q: Quaternion) -> Self {
+            nalgebra::Quaternion::new(q.w, q.x, q.y, q.z)
+        }
+    }
+}
+
+#[cfg(feature = "cgmath")]
+mod cgmath_support {
+    use super::Quaternion;
+
+    impl From<cgmath::Quaternion<f32>> for Quaternion {
+        fn from(q: cgmath::Quaternion<f32>) -> Self {
+            Quaternion::new(q.v.x, q.v.y, q.v.z, q.s)
+        }
+    }
+    impl From<Quaternion> for cgmath::Quaternion<f32> {
+        fn from(q: Quaternion) -> Self {
+            cgmath::Quaternion::new(q.w, q.x, q.y, q.z)
+        }
+    }
+}
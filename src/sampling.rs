@@ -0,0 +1,238 @@
+//! Sampling of directions, disks, spheres and rotations, for path tracers and particle emitters,
+//! and the Halton, Hammersley and Sobol low-discrepancy sequences.
+//!
+//! The mappings from the unit square or cube take their random numbers as arguments, so the same
+//! function works with random numbers and with low-discrepancy points. The `random_*` functions
+//! draw those numbers from a `Random` generator. With the `rand` feature, every
+//! `rand_core::RngCore` is a `Random`.
+
+use crate::math;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector, Vector2, Vector3};
+use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, TAU};
+
+/// Source of uniform random numbers.
+pub trait Random {
+    /// Uniform in `[0, 1)`.
+    fn next_f32(&mut self) -> f32;
+
+    fn next_vector2(&mut self) -> Vector2 {
+        let x = self.next_f32();
+        Vector2::new(x, self.next_f32())
+    }
+
+    fn next_vector3(&mut self) -> Vector3 {
+        let x = self.next_f32();
+        let y = self.next_f32();
+        Vector3::new(x, y, self.next_f32())
+    }
+}
+
+/// Turns the top 24 bits of `bits` into a float in `[0, 1)`.
+fn unit_f32(bits: u32) -> f32 {
+    (bits >> 8) as f32 * (1.0 / (1u32 << 24) as f32)
+}
+
+#[cfg(feature = "rand")]
+impl<R: rand_core::RngCore + ?Sized> Random for R {
+    fn next_f32(&mut self) -> f32 {
+        unit_f32(self.next_u32())
+    }
+}
+
+/// Uniform on the unit sphere.
+pub fn uniform_sphere(u: Vector2) -> Vector3 {
+    let z = 1.0 - 2.0 * u.x;
+    let r = math::sqrt((1.0 - z * z).max(0.0));
+    let (sin, cos) = math::sin_cos(TAU * u.y);
+    Vector3::new(r * cos, r * sin, z)
+}
+
+/// Uniform inside the unit sphere.
+pub fn uniform_ball(u: Vector3) -> Vector3 {
+    uniform_sphere(u.truncate()) * math::powf(u.z, 1.0 / 3.0)
+}
+
+/// Uniform inside the unit disk, by Shirley and Chiu's concentric mapping, which keeps
+/// neighbouring points of the square together and so keeps the stratification of the input.
+pub fn concentric_disk(u: Vector2) -> Vector2 {
+    let x = 2.0 * u.x - 1.0;
+    let y = 2.0 * u.y - 1.0;
+    if x == 0.0 && y == 0.0 {
+        return Vector2::new(0.0, 0.0);
+    }
+
+    let (r, theta) = if x.abs() > y.abs() {
+        (x, FRAC_PI_4 * (y / x))
+    } else {
+        (y, FRAC_PI_2 - FRAC_PI_4 * (x / y))
+    };
+    let (sin, cos) = math::sin_cos(theta);
+    Vector2::new(r * cos, r * sin)
+}
+
+/// Cosine-weighted direction in the hemisphere around `normal`, which must be normalized. The
+/// density is `cos / PI`, where `cos` is the dot product with `normal`.
+pub fn cosine_hemisphere(u: Vector2, normal: &Vector3) -> Vector3 {
+    let d = concentric_disk(u);
+    let z = math::sqrt((1.0 - d.length_sq()).max(0.0));
+    let (tangent, bitangent) = orthonormal_basis(normal);
+    tangent * d.x + bitangent * d.y + *normal * z
+}
+
+/// Two unit vectors that make a right-handed orthonormal basis with `n`, by Duff et al.
+fn orthonormal_basis(n: &Vector3) -> (Vector3, Vector3) {
+    let sign = if n.z < 0.0 { -1.0 } else { 1.0 };
+    let a = -1.0 / (sign + n.z);
+    let b = n.x * n.y * a;
+    (
+        Vector3::new(1.0 + sign * n.x * n.x * a, sign * b, -sign * n.x),
+        Vector3::new(b, sign + n.y * n.y * a, -n.y),
+    )
+}
+
+/// Uniformly distributed rotation, by Shoemake's method.
+pub fn uniform_rotation(u: Vector3) -> Quaternion {
+    let a = math::sqrt(1.0 - u.x);
+    let b = math::sqrt(u.x);
+    let (sin_y, cos_y) = math::sin_cos(TAU * u.y);
+    let (sin_z, cos_z) = math::sin_cos(TAU * u.z);
+    Quaternion::new(a * sin_y, a * cos_y, b * sin_z, b * cos_z)
+}
+
+pub fn random_unit_vector<R: Random + ?Sized>(rng: &mut R) -> Vector3 {
+    uniform_sphere(rng.next_vector2())
+}
+
+pub fn random_in_sphere<R: Random + ?Sized>(rng: &mut R) -> Vector3 {
+    uniform_ball(rng.next_vector3())
+}
+
+pub fn random_in_disk<R: Random + ?Sized>(rng: &mut R) -> Vector2 {
+    concentric_disk(rng.next_vector2())
+}
+
+pub fn random_cosine_hemisphere<R: Random + ?Sized>(rng: &mut R, normal: &Vector3) -> Vector3 {
+    cosine_hemisphere(rng.next_vector2(), normal)
+}
+
+pub fn random_rotation<R: Random + ?Sized>(rng: &mut R) -> Quaternion {
+    uniform_rotation(rng.next_vector3())
+}
+
+pub fn random_rotation_matrix<R: Random + ?Sized>(rng: &mut R) -> Matrix {
+    random_rotation(rng).to_matrix()
+}
+
+/// Bases of `halton`, the first primes.
+const PRIMES: [u32; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// Mirrors the digits of `index` in `base` around the radix point.
+///
+/// Panics if `base` is less than 2.
+pub fn radical_inverse(base: u32, mut index: u32) -> f32 {
+    assert!(base >= 2, "radical inverse base must be at least 2");
+    let inv_base = 1.0 / base as f64;
+    let mut scale = inv_base;
+    let mut result = 0.0f64;
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inv_base;
+    }
+    // Rounding to f32 may reach 1
+    (result as f32).min(1.0 - f32::EPSILON / 2.0)
+}
+
+/// Coordinate `dimension` of point `index` of the Halton sequence, using the prime of that
+/// dimension as the base.
+///
+/// Panics if `dimension` is 16 or more. The higher dimensions of Halton are badly correlated
+/// anyway.
+pub fn halton(index: u32, dimension: usize) -> f32 {
+    radical_inverse(PRIMES[dimension], index)
+}
+
+pub fn halton2(index: u32) -> Vector2 {
+    Vector2::new(halton(index, 0), halton(index, 1))
+}
+
+pub fn halton3(index: u32) -> Vector3 {
+    Vector3::new(halton(index, 0), halton(index, 1), halton(index, 2))
+}
+
+/// Point `index` of a Hammersley set of `count` points: `index / count`, then the base 2
+/// radical inverse. Unlike Halton, the whole set has to be known up front.
+///
+/// Panics if `count` is 0.
+pub fn hammersley(index: u32, count: u32) -> Vector2 {
+    assert!(count > 0, "a Hammersley set has at least one point");
+    Vector2::new(index as f32 / count as f32, unit_f32(index.reverse_bits()))
+}
+
+/// Number of dimensions of `sobol`.
+pub const SOBOL_DIMENSIONS: usize = 4;
+
+/// Direction numbers of a Sobol dimension, from the degree `s`, the coefficients `a` and the
+/// initial numbers `m` of its primitive polynomial, as listed by Joe and Kuo.
+const fn sobol_directions(s: usize, a: u32, m: [u32; 3]) -> [u32; 32] {
+    let mut v = [0u32; 32];
+    let mut i = 0;
+    while i < 32 {
+        v[i] = if s == 0 {
+            1 << (31 - i)
+        } else if i < s {
+            m[i] << (31 - i)
+        } else {
+            let mut x = v[i - s] ^ (v[i - s] >> s);
+            let mut k = 1;
+            while k < s {
+                x ^= ((a >> (s - 1 - k)) & 1) * v[i - k];
+                k += 1;
+            }
+            x
+        };
+        i += 1;
+    }
+    v
+}
+
+const SOBOL: [[u32; 32]; SOBOL_DIMENSIONS] = [
+    sobol_directions(0, 0, [0, 0, 0]),
+    sobol_directions(1, 0, [1, 0, 0]),
+    sobol_directions(2, 1, [1, 3, 0]),
+    sobol_directions(3, 1, [1, 3, 1]),
+];
+
+/// Coordinate `dimension` of point `index` of the Sobol sequence, XORed with `scramble`. A
+/// random `scramble` per pixel or per emitter decorrelates sequences that would otherwise be
+/// identical, and 0 gives the plain sequence.
+///
+/// Panics if `dimension` is `SOBOL_DIMENSIONS` or more.
+pub fn sobol(index: u32, dimension: usize, scramble: u32) -> f32 {
+    let directions = &SOBOL[dimension];
+    let mut result = scramble;
+    let mut index = index;
+    let mut i = 0;
+    while index != 0 {
+        if index & 1 != 0 {
+            result ^= directions[i];
+        }
+        index >>= 1;
+        i += 1;
+    }
+    unit_f32(result)
+}
+
+pub fn sobol2(index: u32, scramble: u32) -> Vector2 {
+    Vector2::new(sobol(index, 0, scramble), sobol(index, 1, scramble))
+}
+
+pub fn sobol3(index: u32, scramble: u32) -> Vector3 {
+    Vector3::new(
+        sobol(index, 0, scramble),
+        sobol(index, 1, scramble),
+        sobol(index, 2, scramble),
+    )
+}
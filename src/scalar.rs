@@ -0,0 +1,267 @@
+//! Port of the DirectXMath scalar functions, `XMScalarSin` and friends.
+//!
+//! The `_est` versions use lower degree polynomials. They are faster, and still good enough for
+//! building rotations of game objects. Error bounds are measured over the given input range, in
+//! absolute terms.
+
+use crate::dmatrix::DMatrix;
+use crate::math::{self, Float};
+use crate::matrix::Matrix;
+use core::f32::consts::{FRAC_PI_2, PI, TAU};
+use core::fmt::Debug;
+use core::ops::*;
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Component type of the vector and matrix types, either `f32` or `f64`.
+pub trait Scalar:
+    sealed::Sealed
+    + Copy
+    + PartialOrd
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// 4x4 matrix with the same component type.
+    type Matrix;
+
+    const ZERO: Self;
+    const ONE: Self;
+    /// 2π
+    const TAU: Self;
+
+    fn abs(self) -> Self;
+    /// Half way cases away from zero.
+    fn round(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    /// Angle of the point (x, self), in [-π, π].
+    fn atan2(self, x: Self) -> Self;
+    fn exp(self) -> Self;
+    /// Natural logarithm.
+    fn ln(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn exp2(self) -> Self;
+    fn log2(self) -> Self;
+}
+
+macro_rules! scalar {
+    ($ty:ident, $matrix:ident) => {
+        impl Scalar for $ty {
+            type Matrix = $matrix;
+
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TAU: Self = core::$ty::consts::TAU;
+
+            fn abs(self) -> Self {
+                $ty::abs(self)
+            }
+
+            fn round(self) -> Self {
+                math::round(self)
+            }
+
+            fn sqrt(self) -> Self {
+                math::sqrt(self)
+            }
+
+            fn sin(self) -> Self {
+                Float::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                Float::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                Float::tan(self)
+            }
+
+            fn atan2(self, x: Self) -> Self {
+                Float::atan2(self, x)
+            }
+
+            fn exp(self) -> Self {
+                Float::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                Float::ln(self)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                math::powf(self, n)
+            }
+
+            fn exp2(self) -> Self {
+                Float::exp2(self)
+            }
+
+            fn log2(self) -> Self {
+                Float::log2(self)
+            }
+        }
+    };
+}
+
+scalar!(f32, Matrix);
+scalar!(f64, DMatrix);
+
+/// `XMScalarModAngle`. Wraps an angle to [-π, π]. Odd multiples of π may land on either end.
+pub fn mod_angle(angle: f32) -> f32 {
+    let angle = angle + PI;
+    let wrapped = angle.abs();
+    let wrapped = wrapped - TAU * ((wrapped / TAU) as i32 as f32) - PI;
+    if angle < 0.0 {
+        -wrapped
+    } else {
+        wrapped
+    }
+}
+
+/// Maps `value` to [-π/2, π/2] with the same sine. The second value is the sign of the cosine.
+fn reduce(value: f32) -> (f32, f32) {
+    let quotient = value * (0.5 / PI);
+    let quotient = if value >= 0.0 {
+        (quotient + 0.5) as i32 as f32
+    } else {
+        (quotient - 0.5) as i32 as f32
+    };
+    let y = value - TAU * quotient;
+
+    if y > FRAC_PI_2 {
+        (PI - y, -1.0)
+    } else if y < -FRAC_PI_2 {
+        (-PI - y, -1.0)
+    } else {
+        (y, 1.0)
+    }
+}
+
+fn sin_poly(y: f32) -> f32 {
+    let y2 = y * y;
+    (((((-2.3889859e-08 * y2 + 2.7525562e-06) * y2 - 0.00019840874) * y2 + 0.008333331) * y2
+        - 0.16666667)
+        * y2
+        + 1.0)
+        * y
+}
+
+fn cos_poly(y: f32) -> f32 {
+    let y2 = y * y;
+    ((((-2.6051615e-07 * y2 + 2.4760495e-05) * y2 - 0.0013888378) * y2 + 0.041666638) * y2 - 0.5)
+        * y2
+        + 1.0
+}
+
+fn sin_poly_est(y: f32) -> f32 {
+    let y2 = y * y;
+    (((-0.0001852467 * y2 + 0.00831395) * y2 - 0.16665852) * y2 + 1.0) * y
+}
+
+fn cos_poly_est(y: f32) -> f32 {
+    let y2 = y * y;
+    ((-0.0012712436 * y2 + 0.04149392) * y2 - 0.49992746) * y2 + 1.0
+}
+
+/// `XMScalarSin`. 11 degree minimax polynomial. Max error 2e-7 for inputs in [-π, π], growing
+/// with the magnitude of the input.
+pub fn sin(value: f32) -> f32 {
+    sin_poly(reduce(value).0)
+}
+
+/// `XMScalarSinEst`. 7 degree minimax polynomial. Max error 2e-6 for inputs in [-π, π].
+pub fn sin_est(value: f32) -> f32 {
+    sin_poly_est(reduce(value).0)
+}
+
+/// `XMScalarCos`. 10 degree minimax polynomial. Max error 3e-7 for inputs in [-π, π], growing
+/// with the magnitude of the input.
+pub fn cos(value: f32) -> f32 {
+    let (y, sign) = reduce(value);
+    sign * cos_poly(y)
+}
+
+/// `XMScalarCosEst`. 6 degree minimax polynomial. Max error 1e-5 for inputs in [-π, π].
+pub fn cos_est(value: f32) -> f32 {
+    let (y, sign) = reduce(value);
+    sign * cos_poly_est(y)
+}
+
+/// `XMScalarSinCos`. Same precision as `sin` and `cos`, for the price of one range reduction.
+pub fn sin_cos(value: f32) -> (f32, f32) {
+    let (y, sign) = reduce(value);
+    (sin_poly(y), sign * cos_poly(y))
+}
+
+/// `XMScalarSinCosEst`. Same precision as `sin_est` and `cos_est`.
+pub fn sin_cos_est(value: f32) -> (f32, f32) {
+    let (y, sign) = reduce(value);
+    (sin_poly_est(y), sign * cos_poly_est(y))
+}
+
+/// acos(|x|) from a polynomial in |x|, then shifted into the range of `asin` or `acos`.
+fn acos_abs(value: f32, poly: impl Fn(f32) -> f32) -> (f32, bool) {
+    let x = value.abs();
+    let root = math::sqrt((1.0 - x).max(0.0));
+    (poly(x) * root, value >= 0.0)
+}
+
+fn acos_poly(x: f32) -> f32 {
+    ((((((-0.0012624911 * x + 0.00667009) * x - 0.017088126) * x + 0.03089188) * x - 0.050174303)
+        * x
+        + 0.08897899)
+        * x
+        - 0.2145988)
+        * x
+        + FRAC_PI_2
+}
+
+fn acos_poly_est(x: f32) -> f32 {
+    ((-0.0187293 * x + 0.074261) * x - 0.2121144) * x + 1.5707288
+}
+
+/// `XMScalarASin`. 7 degree minimax polynomial. Max error 3e-7 for inputs in [-1, 1], which
+/// are clamped to that range.
+pub fn asin(value: f32) -> f32 {
+    match acos_abs(value, acos_poly) {
+        (acos, true) => FRAC_PI_2 - acos,
+        (acos, false) => acos - FRAC_PI_2,
+    }
+}
+
+/// `XMScalarASinEst`. 3 degree minimax polynomial. Max error 7e-5 for inputs in [-1, 1].
+pub fn asin_est(value: f32) -> f32 {
+    match acos_abs(value, acos_poly_est) {
+        (acos, true) => FRAC_PI_2 - acos,
+        (acos, false) => acos - FRAC_PI_2,
+    }
+}
+
+/// `XMScalarACos`. 7 degree minimax polynomial. Max error 4e-7 for inputs in [-1, 1], which
+/// are clamped to that range.
+pub fn acos(value: f32) -> f32 {
+    match acos_abs(value, acos_poly) {
+        (acos, true) => acos,
+        (acos, false) => PI - acos,
+    }
+}
+
+/// `XMScalarACosEst`. 3 degree minimax polynomial. Max error 7e-5 for inputs in [-1, 1].
+pub fn acos_est(value: f32) -> f32 {
+    match acos_abs(value, acos_poly_est) {
+        (acos, true) => acos,
+        (acos, false) => PI - acos,
+    }
+}
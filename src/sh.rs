@@ -0,0 +1,399 @@
+//! Port of the DirectXSH spherical harmonics library, `XMSHEvalDirection` and friends.
+//!
+//! A function of order `n` has `n * n` coefficients, band `l` being at indices `l * l` to
+//! `l * l + 2 * l`. The basis is real and orthonormal, with the Condon-Shortley phase, so it
+//! matches the coefficients produced by DirectXSH and D3DX. Orders 2 to 6 are supported.
+//!
+//! The light functions write one set of coefficients per color channel. `green` and `blue` are
+//! optional, for monochrome lighting.
+
+use crate::color::Color;
+use crate::math;
+use crate::matrix::Matrix;
+use crate::vector::{Vector, Vector3};
+use core::f32::consts::{PI, SQRT_2};
+
+pub const MIN_ORDER: usize = 2;
+pub const MAX_ORDER: usize = 6;
+
+/// Number of coefficients, checking that `order` is supported.
+fn count(order: usize) -> usize {
+    assert!(
+        (MIN_ORDER..=MAX_ORDER).contains(&order),
+        "SH order must be in [{}, {}], got {}",
+        MIN_ORDER,
+        MAX_ORDER,
+        order
+    );
+    order * order
+}
+
+/// Normalization of the band `l`, order `m` basis function, without the √2 of m ≠ 0.
+fn normalization(l: usize, m: usize) -> f32 {
+    let ratio = (l - m + 1..=l + m).fold(1.0, |acc, i| acc / i as f32);
+    math::sqrt((2 * l + 1) as f32 / (4.0 * PI) * ratio)
+}
+
+/// `XMSHEvalDirection`. Evaluates the basis functions in the normalized direction `dir`.
+///
+/// Panics if `order` is not supported or `result` has fewer than `order * order` elements.
+pub fn eval_direction(order: usize, dir: &Vector3, result: &mut [f32]) {
+    let result = &mut result[..count(order)];
+    let Vector3 { x, y, z, .. } = *dir;
+
+    // cos(mφ) and sin(mφ) times sin(θ)^m, as polynomials in x and y
+    let (mut c, mut s) = (1.0, 0.0);
+    // Associated Legendre P(m, m) divided by sin(θ)^m
+    let mut pmm = 1.0;
+    for m in 0..order {
+        if m > 0 {
+            (c, s) = (x * c - y * s, x * s + y * c);
+            pmm *= -((2 * m - 1) as f32);
+        }
+
+        let (mut p1, mut p2) = (0.0, 0.0);
+        for l in m..order {
+            let p = if l == m {
+                pmm
+            } else {
+                ((2 * l - 1) as f32 * z * p1 - (l + m - 1) as f32 * p2) / (l - m) as f32
+            };
+            let k = normalization(l, m) * p;
+            let center = l * l + l;
+            if m == 0 {
+                result[center] = k;
+            } else {
+                result[center + m] = SQRT_2 * k * c;
+                result[center - m] = SQRT_2 * k * s;
+            }
+            (p2, p1) = (p1, p);
+        }
+    }
+}
+
+/// Band `l` rotation, indexed by `[m + l][n + l]`.
+type BandMatrix = [[f32; 2 * MAX_ORDER - 1]; 2 * MAX_ORDER - 1];
+
+/// One step of the Ivanic-Ruedenberg recurrence, without the Condon-Shortley phase.
+struct Recurrence<'a> {
+    r1: &'a BandMatrix,
+    prev: &'a BandMatrix,
+    l: isize,
+}
+
+impl Recurrence<'_> {
+    fn r1(&self, i: isize, j: isize) -> f32 {
+        self.r1[(i + 1) as usize][(j + 1) as usize]
+    }
+
+    fn prev(&self, a: isize, b: isize) -> f32 {
+        let l = self.l - 1;
+        self.prev[(a + l) as usize][(b + l) as usize]
+    }
+
+    fn p(&self, i: isize, a: isize, b: isize) -> f32 {
+        let l = self.l;
+        if b == l {
+            self.r1(i, 1) * self.prev(a, l - 1) - self.r1(i, -1) * self.prev(a, 1 - l)
+        } else if b == -l {
+            self.r1(i, 1) * self.prev(a, 1 - l) + self.r1(i, -1) * self.prev(a, l - 1)
+        } else {
+            self.r1(i, 0) * self.prev(a, b)
+        }
+    }
+
+    fn element(&self, m: isize, n: isize) -> f32 {
+        let l = self.l;
+        let denom = if n.abs() == l {
+            (2 * l * (2 * l - 1)) as f32
+        } else {
+            ((l + n) * (l - n)) as f32
+        };
+        let am = m.abs();
+
+        let mut sum = 0.0;
+        let u = math::sqrt(((l + m) * (l - m)) as f32 / denom);
+        if u != 0.0 {
+            sum += u * self.p(0, m, n);
+        }
+
+        let v = match m {
+            0 => math::sqrt(2.0 * ((l - 1) * l) as f32 / denom) * -0.5,
+            _ => 0.5 * math::sqrt(((l + am - 1) * (l + am)) as f32 / denom),
+        };
+        let v_term = match m {
+            0 => self.p(1, 1, n) + self.p(-1, -1, n),
+            1 => self.p(1, 0, n) * SQRT_2,
+            -1 => self.p(-1, 0, n) * SQRT_2,
+            _ if m > 0 => self.p(1, m - 1, n) - self.p(-1, 1 - m, n),
+            _ => self.p(1, m + 1, n) + self.p(-1, -m - 1, n),
+        };
+        sum += v * v_term;
+
+        if m != 0 && am < l - 1 {
+            let w = -0.5 * math::sqrt(((l - am - 1) * (l - am)) as f32 / denom);
+            let w_term = if m > 0 {
+                self.p(1, m + 1, n) + self.p(-1, -m - 1, n)
+            } else {
+                self.p(1, m - 1, n) - self.p(-1, 1 - m, n)
+            };
+            sum += w * w_term;
+        }
+        sum
+    }
+}
+
+/// `XMSHRotate`. Rotates the function so that its value in direction `d` moves to
+/// `d.to_homogeneous_dir().transform(rotation)`. Only the upper 3x3 of `rotation` is used, and
+/// must be a rotation.
+///
+/// Panics if `order` is not supported or a slice has fewer than `order * order` elements.
+pub fn rotate(order: usize, rotation: &Matrix, input: &[f32], result: &mut [f32]) {
+    let n = count(order);
+    let (input, result) = (&input[..n], &mut result[..n]);
+    result[0] = input[0];
+
+    // Band 1 in (y, z, x) order, for column vectors. Without the Condon-Shortley phase this is
+    // the rotation matrix itself.
+    const AXES: [usize; 3] = [1, 2, 0];
+    let mut r1 = BandMatrix::default();
+    for (i, &a) in AXES.iter().enumerate() {
+        for (j, &b) in AXES.iter().enumerate() {
+            r1[i][j] = rotation[b][a];
+        }
+    }
+
+    let mut prev = r1;
+    for l in 1..order {
+        let band = if l == 1 {
+            r1
+        } else {
+            let recurrence = Recurrence {
+                r1: &r1,
+                prev: &prev,
+                l: l as isize,
+            };
+            let mut band = BandMatrix::default();
+            for (i, row) in band.iter_mut().take(2 * l + 1).enumerate() {
+                for (j, e) in row.iter_mut().take(2 * l + 1).enumerate() {
+                    *e = recurrence.element(i as isize - l as isize, j as isize - l as isize);
+                }
+            }
+            band
+        };
+
+        // (-1)^(m + n) brings back the Condon-Shortley phase
+        let first = l * l;
+        for i in 0..2 * l + 1 {
+            let mut sum = 0.0;
+            for j in 0..2 * l + 1 {
+                let e = band[i][j];
+                sum += if (i + j) % 2 == 0 { e } else { -e } * input[first + j];
+            }
+            result[first + i] = sum;
+        }
+        prev = band;
+    }
+}
+
+/// `XMSHAdd`
+pub fn add(order: usize, a: &[f32], b: &[f32], result: &mut [f32]) {
+    let n = count(order);
+    for ((r, a), b) in result[..n].iter_mut().zip(&a[..n]).zip(&b[..n]) {
+        *r = a + b;
+    }
+}
+
+/// `XMSHScale`
+pub fn scale(order: usize, input: &[f32], scale: f32, result: &mut [f32]) {
+    let n = count(order);
+    for (r, x) in result[..n].iter_mut().zip(&input[..n]) {
+        *r = x * scale;
+    }
+}
+
+/// `XMSHDot`. Integral of the product of the two functions over the sphere.
+pub fn dot(order: usize, a: &[f32], b: &[f32]) -> f32 {
+    let n = count(order);
+    a[..n].iter().zip(&b[..n]).map(|(a, b)| a * b).sum()
+}
+
+/// Convolution of band `l` with the clamped cosine, up to the highest supported band.
+const COSINE_LOBE: [f32; MAX_ORDER] = [PI, 2.0 * PI / 3.0, PI / 4.0, 0.0, -PI / 24.0, 0.0];
+
+/// Integrals of the Legendre polynomials from cos(θ) to 1, for the bands up to `order`. Uses
+/// `sin(θ)² P'(cos(θ)) / (l (l + 1))` instead of differences of the polynomials, which cancel
+/// out for small caps.
+fn cap_integrals(order: usize, sin: f32, cos: f32) -> [f32; MAX_ORDER] {
+    let mut integrals = [0.0; MAX_ORDER];
+    let sin2 = sin * sin;
+    integrals[0] = if cos > 0.0 {
+        sin2 / (1.0 + cos)
+    } else {
+        1.0 - cos
+    };
+
+    // P(l - 1), P(l), and the derivatives P'(l - 1), P'(l)
+    let (mut p0, mut p1) = (1.0, cos);
+    let (mut d0, mut d1) = (0.0, 1.0);
+    for (l, integral) in integrals.iter_mut().enumerate().take(order).skip(1) {
+        *integral = sin2 * d1 / (l * (l + 1)) as f32;
+        let p2 = ((2 * l + 1) as f32 * cos * p1 - l as f32 * p0) / (l + 1) as f32;
+        let d2 = d0 + (2 * l + 1) as f32 * p1;
+        (p0, p1, d0, d1) = (p1, p2, d1, d2);
+    }
+    integrals
+}
+
+/// Projects a cap of constant unit radiance around `dir` with the given sine and cosine of its
+/// half angle, writing `order * order` coefficients. Returns the exit radiance of a white diffuse
+/// surface facing the cap, as seen through the projection.
+fn project_cap(
+    order: usize,
+    dir: &Vector3,
+    sin: f32,
+    cos: f32,
+    result: &mut [f32; MAX_ORDER * MAX_ORDER],
+) -> f32 {
+    eval_direction(order, dir, result);
+    let integrals = cap_integrals(order, sin, cos);
+
+    let mut exit = 0.0;
+    for l in 0..order {
+        let zonal = 2.0 * PI * integrals[l];
+        for e in &mut result[l * l..(l + 1) * (l + 1)] {
+            *e *= zonal;
+        }
+        exit += COSINE_LOBE[l] * (2 * l + 1) as f32 / (4.0 * PI) * zonal;
+    }
+    exit / PI
+}
+
+fn write_channels(
+    order: usize,
+    coefficients: &[f32],
+    color: &Color,
+    red: &mut [f32],
+    green: Option<&mut [f32]>,
+    blue: Option<&mut [f32]>,
+) {
+    scale(order, coefficients, color.r, red);
+    if let Some(green) = green {
+        scale(order, coefficients, color.g, green);
+    }
+    if let Some(blue) = blue {
+        scale(order, coefficients, color.b, blue);
+    }
+}
+
+/// `XMSHEvalDirectionalLight`. Light arriving from the normalized direction `dir`, which points
+/// towards the light. Scaled so that a white diffuse surface facing the light has an exit
+/// radiance of `color`, as seen through the projection.
+pub fn eval_directional_light(
+    order: usize,
+    dir: &Vector3,
+    color: &Color,
+    red: &mut [f32],
+    green: Option<&mut [f32]>,
+    blue: Option<&mut [f32]>,
+) {
+    let mut basis = [0.0; MAX_ORDER * MAX_ORDER];
+    eval_direction(order, dir, &mut basis);
+
+    let cosine_sum: f32 = (0..order)
+        .map(|l| COSINE_LOBE[l] * (2 * l + 1) as f32 / (4.0 * PI))
+        .sum();
+    let n = order * order;
+    for e in &mut basis[..n] {
+        *e *= PI / cosine_sum;
+    }
+    write_channels(order, &basis, color, red, green, blue);
+}
+
+/// `XMSHEvalSphericalLight`. Sphere of constant radiance `color`, centered at `pos` relative to
+/// the point being lit. Unlike the other lights it is not normalized, so the intensity falls
+/// off with distance. A point inside the sphere gets ambient light.
+pub fn eval_spherical_light(
+    order: usize,
+    pos: &Vector3,
+    radius: f32,
+    color: &Color,
+    red: &mut [f32],
+    green: Option<&mut [f32]>,
+    blue: Option<&mut [f32]>,
+) {
+    let dist = pos.length();
+    let (dir, sin, cos) = if dist <= radius {
+        (Vector3::Z, 0.0, -1.0)
+    } else {
+        let sin = radius / dist;
+        (*pos / dist, sin, math::sqrt(1.0 - sin * sin))
+    };
+
+    let mut cap = [0.0; MAX_ORDER * MAX_ORDER];
+    project_cap(order, &dir, sin, cos, &mut cap);
+    write_channels(order, &cap, color, red, green, blue);
+}
+
+/// `XMSHEvalConeLight`. Cone of constant radiance around the normalized direction `dir`, with
+/// a half angle of `radius` radians in [0, π]. Normalized like `eval_directional_light`, which
+/// it turns into as the radius goes to 0.
+pub fn eval_cone_light(
+    order: usize,
+    dir: &Vector3,
+    radius: f32,
+    color: &Color,
+    red: &mut [f32],
+    green: Option<&mut [f32]>,
+    blue: Option<&mut [f32]>,
+) {
+    if radius < 1e-4 {
+        return eval_directional_light(order, dir, color, red, green, blue);
+    }
+
+    let mut cap = [0.0; MAX_ORDER * MAX_ORDER];
+    let (sin, cos) = math::sin_cos(radius);
+    let exit = project_cap(order, dir, sin, cos, &mut cap);
+    let n = order * order;
+    for e in &mut cap[..n] {
+        *e /= exit;
+    }
+    write_channels(order, &cap, color, red, green, blue);
+}
+
+/// `XMSHEvalHemisphereLight`. Radiance blending linearly from `bottom` in the direction
+/// opposite to the normalized `dir` to `top` in direction `dir`, linear in the cosine to `dir`.
+/// Not normalized: a diffuse surface facing `dir` under a white top and a black bottom has an
+/// exit radiance of 5/6, while equal colors give that color.
+pub fn eval_hemisphere_light(
+    order: usize,
+    dir: &Vector3,
+    top: &Color,
+    bottom: &Color,
+    red: &mut [f32],
+    green: Option<&mut [f32]>,
+    blue: Option<&mut [f32]>,
+) {
+    let n = count(order);
+    let mut basis = [0.0; MAX_ORDER * MAX_ORDER];
+    eval_direction(order, dir, &mut basis);
+
+    // The average is the constant band. Half the difference times the cosine only projects to
+    // band 1, where cos = 4π/3 Σ Y1m(dir) Y1m.
+    let channel = |top: f32, bottom: f32, result: &mut [f32]| {
+        let (average, half_difference) = (0.5 * (top + bottom), 0.5 * (top - bottom));
+        let result = &mut result[..n];
+        result.fill(0.0);
+        result[0] = average * 2.0 * math::sqrt(PI);
+        for i in 1..4 {
+            result[i] = half_difference * 4.0 * PI / 3.0 * basis[i];
+        }
+    };
+    channel(top.r, bottom.r, red);
+    if let Some(green) = green {
+        channel(top.g, bottom.g, green);
+    }
+    if let Some(blue) = blue {
+        channel(top.b, bottom.b, blue);
+    }
+}
@@ -0,0 +1,148 @@
+//! Cascaded shadow maps for directional lights: split distances, light matrices fitted to a
+//! slice of the camera frustum, and texel snapping.
+//!
+//! Distances are positive depths in front of the camera, as `near_z` and `far_z` of the
+//! projection matrices.
+
+use crate::math;
+use crate::matrix::Matrix;
+use crate::vector::{Vector, Vector3, Vector4};
+
+/// Light view and orthographic bounds fitted to a frustum slice. The bounds are in light view
+/// space, with the same meaning as the arguments of `Matrix::orthographic_off_center`, so they
+/// can be adjusted before building `projection`, for example to pull `near_z` back towards
+/// casters outside the slice.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ShadowFit {
+    pub view: Matrix,
+    pub left: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub top: f32,
+    pub near_z: f32,
+    pub far_z: f32,
+}
+
+impl ShadowFit {
+    /// `Matrix::orthographic_off_center` with the fitted bounds.
+    pub fn projection(&self) -> Matrix {
+        Matrix::orthographic_off_center(
+            self.left,
+            self.right,
+            self.bottom,
+            self.top,
+            self.near_z,
+            self.far_z,
+        )
+    }
+
+    pub fn view_projection(&self) -> Matrix {
+        self.view * self.projection()
+    }
+
+    /// Moves the bounds to whole texels of a `resolution` x `resolution` shadow map. As the light
+    /// view does not follow the camera, the texel grid stays put in the world and shadow edges
+    /// stop shimmering while the camera moves. Turning the camera still changes the size of a
+    /// tight fit, and so the size of the texels.
+    ///
+    /// The texel size leaves one texel of slack, so after snapping the minimum down, the bounds
+    /// span exactly `resolution` texels and still enclose the fit.
+    ///
+    /// Panics if `resolution` is less than 2.
+    pub fn snap_to_texels(&mut self, resolution: u32) {
+        assert!(
+            resolution >= 2,
+            "a shadow map needs at least 2 texels per side"
+        );
+        let texels = resolution as f32;
+        let texel_x = (self.right - self.left) / (texels - 1.0);
+        let texel_y = (self.top - self.bottom) / (texels - 1.0);
+        self.left = math::floor(self.left / texel_x) * texel_x;
+        self.right = self.left + texels * texel_x;
+        self.bottom = math::floor(self.bottom / texel_y) * texel_y;
+        self.top = self.bottom + texels * texel_y;
+    }
+}
+
+/// Far distance of each cascade between `near` and `far`, one per element of `splits`. Blends
+/// the logarithmic split scheme, which matches the perspective texel density, with the uniform
+/// one by `lambda`: 1 is fully logarithmic, 0 fully uniform. Cascade `i` covers
+/// `splits[i - 1]..splits[i]`, starting from `near`, and the last one ends at `far`.
+pub fn cascade_splits(near: f32, far: f32, lambda: f32, splits: &mut [f32]) {
+    let count = splits.len() as f32;
+    for (i, split) in splits.iter_mut().enumerate() {
+        let t = (i + 1) as f32 / count;
+        let log = near * math::powf(far / near, t);
+        let uniform = near + (far - near) * t;
+        *split = uniform + (log - uniform) * lambda;
+    }
+    if let Some(last) = splits.last_mut() {
+        *last = far;
+    }
+}
+
+/// World space corners of the part of the view frustum between the `near` and `far`
+/// distances: the four near corners, then the four far ones. Works for any invertible
+/// `projection`, perspective or orthographic.
+///
+/// Panics if `view` or `projection` is singular.
+pub fn frustum_corners(view: &Matrix, projection: &Matrix, near: f32, far: f32) -> [Vector3; 8] {
+    let inverse_view = view.inverse().expect("invertible view");
+    let inverse_projection = projection.inverse().expect("invertible projection");
+    let depth = |distance: f32| {
+        let clip = Vector4::new(0.0, 0.0, -distance, 1.0).transform(projection);
+        clip.z / clip.w
+    };
+
+    let mut corners = [Vector3::ZERO; 8];
+    for (i, corner) in corners.iter_mut().enumerate() {
+        let ndc = Vector4::new(
+            if i & 1 == 0 { -1.0 } else { 1.0 },
+            if i & 2 == 0 { -1.0 } else { 1.0 },
+            depth(if i & 4 == 0 { near } else { far }),
+            1.0,
+        );
+        let p = ndc.transform(&inverse_projection);
+        *corner = (p.truncate() / p.w).transform(&inverse_view);
+    }
+    corners
+}
+
+/// Light view and orthographic bounds that tightly enclose the slice of the camera frustum
+/// between the `near` and `far` distances, for a light shining along `light_dir`. The light
+/// view sits at the world origin, so it does not move with the camera and `snap_to_texels`
+/// can keep the texel grid in place.
+pub fn fit_slice(
+    view: &Matrix,
+    projection: &Matrix,
+    light_dir: Vector3,
+    near: f32,
+    far: f32,
+) -> ShadowFit {
+    let light_dir = light_dir.normalize();
+    let up = if light_dir.y.abs() > 0.99 {
+        Vector3::Z
+    } else {
+        Vector3::Y
+    };
+    let light_view = Matrix::look_to(Vector3::ZERO, light_dir, up);
+
+    let mut min = Vector3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY);
+    let mut max = Vector3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY);
+    for corner in frustum_corners(view, projection, near, far) {
+        let p = corner.transform(&light_view);
+        min = min.min(&p);
+        max = max.max(&p);
+    }
+
+    // The light looks down its -z axis, so depths are negated z
+    ShadowFit {
+        view: light_view,
+        left: min.x,
+        right: max.x,
+        bottom: min.y,
+        top: max.y,
+        near_z: -max.z,
+        far_z: -min.z,
+    }
+}
@@ -0,0 +1,153 @@
+//! Swizzles with compile time checked indices, and named swizzles like `v.xzy()`.
+//!
+//! The const generic versions are called `swizzle_const` and `permute_const`, because inherent
+//! methods named `swizzle` and `permute` would hide the runtime versions of `Vector`.
+//!
+//! ```
+//! use xmath::{Vector2, Vector3};
+//!
+//! let v = Vector3::new(1.0, 2.0, 3.0);
+//! assert_eq!(v.swizzle_const::<0, 2, 1>(), v.xzy());
+//! assert_eq!(v.zy(), Vector2::new(3.0, 2.0));
+//! ```
+//!
+//! Out of range indices do not compile:
+//!
+//! ```compile_fail
+//! use xmath::Vector3;
+//!
+//! let _ = Vector3::new(1.0, 2.0, 3.0).swizzle_const::<0, 1, 3>();
+//! ```
+
+use crate::dvector::{DVector2, DVector3, DVector4};
+use crate::vector::{Vector2, Vector3, Vector4};
+
+/// Named swizzles of `$v2`, `$v3` and `$v4`, every name of 2 to 4 letters made of the
+/// components in the list.
+macro_rules! named {
+    ([$($c:ident)+] => $v2:ident, $v3:ident, $v4:ident) => {
+        named!(@names $v2 [$($c)+] [$($c)+] [] (_ (_ ())));
+        named!(@names $v3 [$($c)+] [$($c)+] [] (_ (_ (_ ()))));
+        named!(@names $v4 [$($c)+] [$($c)+] [] (_ (_ (_ (_ ())))));
+    };
+    // Extends the prefix `$p` by every component, until it has as many letters as the count has
+    // `_`s. The component list comes twice, as the whole list is passed down for each component.
+    (@names $ty:ident $all:tt [$($c:ident)+] [$($p:ident)*] ()) => {
+        paste::paste! {
+            pub fn [<$($p)*>](&self) -> $ty {
+                $ty::new($(self.$p),*)
+            }
+        }
+    };
+    (@names $ty:ident $all:tt [$($c:ident)+] $p:tt (_ $count:tt)) => {
+        $(named!(@append $ty $all $p $c $count);)+
+    };
+    (@append $ty:ident $all:tt [$($p:ident)*] $c:ident $count:tt) => {
+        named!(@names $ty $all $all [$($p)* $c] $count);
+    };
+}
+
+macro_rules! swizzles {
+    ($v2:ident, $v3:ident, $v4:ident) => {
+        impl $v2 {
+            /// Compile time checked `swizzle`, every index must be below 2.
+            pub fn swizzle_const<const X: usize, const Y: usize>(&self) -> Self {
+                const { assert!(X < 2 && Y < 2, "swizzle index out of range") };
+                Self::new(self[X], self[Y])
+            }
+
+            /// Compile time checked `permute`. Indices 0 to 3 pick from `self` and 4 to 7 from
+            /// `other`, only the meaningful components can be picked.
+            pub fn permute_const<const X: usize, const Y: usize>(&self, other: &Self) -> Self {
+                const {
+                    assert!(
+                        X < 8 && X % 4 < 2 && Y < 8 && Y % 4 < 2,
+                        "permute index out of range"
+                    )
+                };
+                let pick = |i: usize| if i < 4 { self[i] } else { other[i - 4] };
+                Self::new(pick(X), pick(Y))
+            }
+        }
+
+        impl $v3 {
+            /// Compile time checked `swizzle`, every index must be below 3.
+            pub fn swizzle_const<const X: usize, const Y: usize, const Z: usize>(&self) -> Self {
+                const { assert!(X < 3 && Y < 3 && Z < 3, "swizzle index out of range") };
+                Self::new(self[X], self[Y], self[Z])
+            }
+
+            /// Compile time checked `permute`. Indices 0 to 3 pick from `self` and 4 to 7 from
+            /// `other`, only the meaningful components can be picked.
+            pub fn permute_const<const X: usize, const Y: usize, const Z: usize>(
+                &self,
+                other: &Self,
+            ) -> Self {
+                const {
+                    assert!(
+                        X < 8 && X % 4 < 3 && Y < 8 && Y % 4 < 3 && Z < 8 && Z % 4 < 3,
+                        "permute index out of range"
+                    )
+                };
+                let pick = |i: usize| if i < 4 { self[i] } else { other[i - 4] };
+                Self::new(pick(X), pick(Y), pick(Z))
+            }
+        }
+
+        impl $v4 {
+            /// Compile time checked `swizzle`, every index must be below 4.
+            pub fn swizzle_const<const X: usize, const Y: usize, const Z: usize, const W: usize>(
+                &self,
+            ) -> Self {
+                const {
+                    assert!(
+                        X < 4 && Y < 4 && Z < 4 && W < 4,
+                        "swizzle index out of range"
+                    )
+                };
+                Self::new(self[X], self[Y], self[Z], self[W])
+            }
+
+            /// Compile time checked `permute`. Indices 0 to 3 pick from `self` and 4 to 7 from
+            /// `other`, only the meaningful components can be picked.
+            pub fn permute_const<const X: usize, const Y: usize, const Z: usize, const W: usize>(
+                &self,
+                other: &Self,
+            ) -> Self {
+                const {
+                    assert!(
+                        X < 8
+                            && X % 4 < 4
+                            && Y < 8
+                            && Y % 4 < 4
+                            && Z < 8
+                            && Z % 4 < 4
+                            && W < 8
+                            && W % 4 < 4,
+                        "permute index out of range"
+                    )
+                };
+                let pick = |i: usize| if i < 4 { self[i] } else { other[i - 4] };
+                Self::new(pick(X), pick(Y), pick(Z), pick(W))
+            }
+        }
+
+        /// Named swizzles, which return a vector with as many components as the name has letters.
+        impl $v2 {
+            named!([x y] => $v2, $v3, $v4);
+        }
+
+        /// Named swizzles, which return a vector with as many components as the name has letters.
+        impl $v3 {
+            named!([x y z] => $v2, $v3, $v4);
+        }
+
+        /// Named swizzles, which return a vector with as many components as the name has letters.
+        impl $v4 {
+            named!([x y z w] => $v2, $v3, $v4);
+        }
+    };
+}
+
+swizzles!(Vector2, Vector3, Vector4);
+swizzles!(DVector2, DVector3, DVector4);
@@ -0,0 +1,130 @@
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::vector::{Vector, Vector3};
+use core::ops::*;
+
+/// Scale, then rotation, then translation, the way editors and animation systems store
+/// transformations. `to_matrix` gives the same matrix as
+/// `Matrix::scaling(..) * rotation * Matrix::translation(..)`.
+///
+/// `a * b` transforms by a, then by b, like `Matrix`, so a child's world transform is
+/// `local * parent_world`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Transform {
+    pub translation: Vector3,
+    pub rotation: Quaternion,
+    pub scale: Vector3,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Transform::new(Vector3::ZERO, Quaternion::IDENTITY, Vector3::ONE);
+
+    pub const fn new(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Self {
+        Transform {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    pub const fn from_translation(translation: Vector3) -> Self {
+        Transform::new(translation, Quaternion::IDENTITY, Vector3::ONE)
+    }
+
+    pub const fn from_rotation(rotation: Quaternion) -> Self {
+        Transform::new(Vector3::ZERO, rotation, Vector3::ONE)
+    }
+
+    pub const fn from_scale(scale: Vector3) -> Self {
+        Transform::new(Vector3::ZERO, Quaternion::IDENTITY, scale)
+    }
+
+    /// `XMMatrixDecompose`. `m` must be affine, without shear and without a zero scale. A
+    /// mirroring shows up as a negative x scale.
+    pub fn from_matrix(m: &Matrix) -> Self {
+        let mut rows = [
+            Vector3::new(m[0][0], m[0][1], m[0][2]),
+            Vector3::new(m[1][0], m[1][1], m[1][2]),
+            Vector3::new(m[2][0], m[2][1], m[2][2]),
+        ];
+        let mut scale = Vector3::new(rows[0].length(), rows[1].length(), rows[2].length());
+        if rows[0].dot(&rows[1].cross(&rows[2])) < 0.0 {
+            scale.x = -scale.x;
+        }
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row /= scale[i];
+        }
+
+        let rotation = Matrix::from_rows_array([
+            rows[0].extend(0.0).into(),
+            rows[1].extend(0.0).into(),
+            rows[2].extend(0.0).into(),
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        Transform::new(
+            Vector3::new(m[3][0], m[3][1], m[3][2]),
+            Quaternion::from_rotation_matrix(&rotation),
+            scale,
+        )
+    }
+
+    /// `XMMatrixAffineTransformation` without the rotation origin. `self.rotation` must be
+    /// normalized.
+    pub fn to_matrix(&self) -> Matrix {
+        let mut rows = self.rotation.to_matrix().to_rows_array();
+        for (i, row) in rows.iter_mut().take(3).enumerate() {
+            for e in row.iter_mut().take(3) {
+                *e *= self.scale[i];
+            }
+        }
+        rows[3] = self.translation.extend(1.0).into();
+        Matrix::from_rows_array(rows)
+    }
+
+    /// The inverse transformation. Exact for uniform scales. With a non-uniform scale and a
+    /// rotation the inverse needs a shear, which `Transform` cannot hold, so use
+    /// `to_matrix` for those.
+    pub fn inverse(&self) -> Self {
+        let rotation = self.rotation.conjugate();
+        let scale = self.scale.reciprocal();
+        let translation = -(rotation.rotate(&self.translation) * scale);
+        Transform::new(translation, rotation, scale)
+    }
+
+    pub fn transform_point(&self, p: &Vector3) -> Vector3 {
+        self.rotation.rotate(&(*p * self.scale)) + self.translation
+    }
+
+    /// Scales and rotates `v`, without translating it.
+    pub fn transform_vector(&self, v: &Vector3) -> Vector3 {
+        self.rotation.rotate(&(*v * self.scale))
+    }
+
+    /// Linear interpolation of translation and scale, and `slerp` of the rotation.
+    pub fn lerp(&self, other: &Self, t: f32) -> Self {
+        Transform::new(
+            self.translation + (other.translation - self.translation) * t,
+            self.rotation.slerp(&other.rotation, t),
+            self.scale + (other.scale - self.scale) * t,
+        )
+    }
+}
+
+/// Same as multiplying the matrices, as long as `rhs` has a uniform scale or `self` has no
+/// rotation. Otherwise the shear of the product is dropped, as in most scene graphs.
+impl Mul for Transform {
+    type Output = Transform;
+    fn mul(self, rhs: Transform) -> Transform {
+        Transform::new(
+            rhs.transform_point(&self.translation),
+            self.rotation * rhs.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+}
+
+impl From<Transform> for Matrix {
+    fn from(t: Transform) -> Self {
+        t.to_matrix()
+    }
+}
@@ -1,14 +1,18 @@
+use crate::math;
 use crate::matrix::{Matrix, Row};
-use std::f32;
-use std::ops::*;
+use crate::scalar::Scalar;
+use core::f32;
+use core::ops::*;
 
-pub trait Vector: Sized + Div<f32, Output = Self> {
+/// Operations shared by the vector types. `S` is the component type, `f32` for
+/// `Vector2`/`Vector3`/`Vector4` and `f64` for `DVector2`/`DVector3`/`DVector4`.
+pub trait Vector<S: Scalar = f32>: Sized + Div<S, Output = Self> {
     fn zero() -> Self;
     fn one() -> Self;
     fn infinity() -> Self;
     fn nan() -> Self;
     fn epsilon() -> Self;
-    fn replicate(value: f32) -> Self;
+    fn replicate(value: S) -> Self;
 
     //
     // Comparison operations
@@ -22,11 +26,11 @@ pub trait Vector: Sized + Div<f32, Output = Self> {
     //
     // Computation operations
     //
-    fn dot(&self, other: &Self) -> f32;
-    fn length_sq(&self) -> f32 {
+    fn dot(&self, other: &Self) -> S;
+    fn length_sq(&self) -> S {
         self.dot(self)
     }
-    fn length(&self) -> f32 {
+    fn length(&self) -> S {
         self.length_sq().sqrt()
     }
     fn normalize(self) -> Self {
@@ -44,7 +48,7 @@ pub trait Vector: Sized + Div<f32, Output = Self> {
         permute_z: usize,
     ) -> Self;
 
-    fn transform(&self, matrix: &Matrix) -> Self;
+    fn transform(&self, matrix: &S::Matrix) -> Self;
 
     fn min(&self, other: &Self) -> Self;
     fn max(&self, other: &Self) -> Self;
@@ -61,939 +65,1503 @@ pub trait Vector: Sized + Div<f32, Output = Self> {
     fn splat_y(&self) -> Self;
     fn splat_z(&self) -> Self;
     fn splat_w(&self) -> Self;
-}
-
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Vector2 {
-    pub x: f32,
-    pub y: f32,
-    z: f32,
-    w: f32,
-}
-
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Vector3 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    w: f32,
-}
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub struct Vector4 {
-    pub x: f32,
-    pub y: f32,
-    pub z: f32,
-    pub w: f32,
-}
+    //
+    // Per component functions. These only touch the meaningful components, the hidden lanes of
+    // `Vector2` and `Vector3` stay zero.
+    //
+    /// Applies `f` to each meaningful component. The per component functions below are built on
+    /// this and `zip_map`, so an implementor only has to write these two.
+    fn map(&self, f: impl Fn(S) -> S) -> Self;
+    /// Applies `f` to each pair of meaningful components of `self` and `other`.
+    fn zip_map(&self, other: &Self, f: impl Fn(S, S) -> S) -> Self;
 
-impl Vector2 {
-    pub fn new(x: f32, y: f32) -> Self {
-        Vector2 {
-            x,
-            y,
-            z: 0.0,
-            w: 0.0,
-        }
+    fn sin(&self) -> Self {
+        self.map(S::sin)
     }
-}
-
-impl Vector3 {
-    pub fn new(x: f32, y: f32, z: f32) -> Self {
-        Vector3 { x, y, z, w: 0.0 }
+    fn cos(&self) -> Self {
+        self.map(S::cos)
     }
-
-    pub fn cross(&self, other: &Self) -> Self {
-        Vector3::new(
-            self.y * other.z - self.z * other.y,
-            self.z * other.x - self.x * other.z,
-            self.x * other.y - self.y * other.x,
-        )
+    fn tan(&self) -> Self {
+        self.map(S::tan)
     }
-}
-
-impl Vector4 {
-    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
-        Vector4 { x, y, z, w }
+    /// `XMVectorATan2`. `self` holds the y coordinates.
+    fn atan2(&self, x: &Self) -> Self {
+        self.zip_map(x, S::atan2)
     }
-}
-
-impl Vector for Vector2 {
-    fn zero() -> Self {
-        Self::replicate(0.0)
+    /// `XMVectorExpE`
+    fn exp(&self) -> Self {
+        self.map(S::exp)
     }
-
-    fn one() -> Self {
-        Self::replicate(1.0)
+    /// `XMVectorLogE`, the natural logarithm.
+    fn log(&self) -> Self {
+        self.map(S::ln)
     }
-
-    fn infinity() -> Self {
-        Self::replicate(f32::INFINITY)
+    fn pow(&self, exponent: &Self) -> Self {
+        self.zip_map(exponent, S::powf)
     }
-
-    fn nan() -> Self {
-        Self::replicate(f32::NAN)
+    fn exp2(&self) -> Self {
+        self.map(S::exp2)
     }
-
-    fn epsilon() -> Self {
-        Self::replicate(f32::EPSILON)
+    fn log2(&self) -> Self {
+        self.map(S::log2)
     }
-
-    fn replicate(value: f32) -> Self {
-        Self::new(value, value)
+    fn abs(&self) -> Self {
+        self.map(S::abs)
     }
-
-    fn is_nan(&self) -> bool {
-        self.x.is_nan() || self.y.is_nan()
+    fn sqrt(&self) -> Self {
+        self.map(S::sqrt)
     }
-
-    fn is_infinite(&self) -> bool {
-        self.x.is_infinite() || self.y.is_infinite()
+    /// `XMVectorReciprocal`, 1 / x.
+    fn reciprocal(&self) -> Self {
+        self.map(|x| S::ONE / x)
     }
-
-    fn dot(&self, other: &Self) -> f32 {
-        self.x * other.x + self.y * other.y
-    }
-
-    fn swizzle(&self, e0: usize, e1: usize, _e2: usize, _e3: usize) -> Self {
-        assert!(e0 < 4);
-        assert!(e1 < 4);
-        Self::new(self[e0], self[e1])
-    }
-
-    fn permute(
-        &self,
-        other: &Self,
-        permute_x: usize,
-        permute_y: usize,
-        _permute_z: usize,
-        _permute_w: usize,
-    ) -> Self {
-        assert!(permute_x < 8);
-        assert!(permute_y < 8);
-        let x = if permute_x < 4 {
-            self[permute_x]
-        } else {
-            other[permute_x - 4]
-        };
-        let y = if permute_y < 4 {
-            self[permute_y]
-        } else {
-            other[permute_y - 4]
-        };
-        Self::new(x, y)
+    /// `XMVectorReciprocalSqrt`, 1 / sqrt(x).
+    fn reciprocal_sqrt(&self) -> Self {
+        self.map(|x| S::ONE / x.sqrt())
     }
-
-    fn transform(&self, matrix: &Matrix) -> Self {
-        let x = self.splat_x();
-        let y = self.splat_y();
-
-        let m0 = Self::from(matrix[0]);
-        let m1 = Self::from(matrix[1]);
-        let m3 = Self::from(matrix[3]);
-
-        x * m0 + y * m1 + m3
+    /// `XMVectorSaturate`. Clamps to [0, 1].
+    fn saturate(&self) -> Self {
+        self.map(|x| {
+            if x < S::ZERO {
+                S::ZERO
+            } else if x > S::ONE {
+                S::ONE
+            } else {
+                x
+            }
+        })
     }
-
-    fn min(&self, other: &Self) -> Self {
-        let x = self.x.min(other.x);
-        let y = self.y.min(other.y);
-        Self::new(x, y)
+    /// `XMVectorModAngles`. Wraps angles to [-π, π].
+    fn mod_angles(&self) -> Self {
+        self.map(|x| x - S::TAU * (x / S::TAU).round())
     }
-    fn max(&self, other: &Self) -> Self {
-        let x = self.x.max(other.x);
-        let y = self.y.max(other.y);
-        Self::new(x, y)
-    }
-
-    fn round(&self) -> Self {
-        let x = self.x.round();
-        let y = self.y.round();
-        Self::new(x, y)
-    }
-    fn trunc(&self) -> Self {
-        let x = self.x.trunc();
-        let y = self.y.trunc();
-        Self::new(x, y)
-    }
-    fn floor(&self) -> Self {
-        let x = self.x.floor();
-        let y = self.y.floor();
-        Self::new(x, y)
-    }
-    fn ceil(&self) -> Self {
-        let x = self.x.ceil();
-        let y = self.y.ceil();
-        Self::new(x, y)
-    }
-    fn clamp(&self, min: &Self, max: &Self) -> Self {
-        assert!(min.x < max.x);
-        assert!(min.y < max.y);
-        self.max(min).min(max)
-    }
-
-    fn multiply_add(&self, mul: &Self, add: &Self) -> Self {
-        *self * *mul + *add
-    }
-
-    fn splat_x(&self) -> Self {
-        Self::replicate(self.x)
-    }
-    fn splat_y(&self) -> Self {
-        Self::replicate(self.y)
-    }
-    fn splat_z(&self) -> Self {
-        Self::replicate(0.0)
-    }
-    fn splat_w(&self) -> Self {
-        Self::replicate(0.0)
+    /// Sum of the components.
+    fn sum(&self) -> S {
+        self.dot(&Self::one())
     }
 }
 
-impl Vector for Vector3 {
-    fn zero() -> Self {
-        Self::replicate(0.0)
-    }
-    fn one() -> Self {
-        Self::replicate(1.0)
-    }
-
-    fn infinity() -> Self {
-        Self::replicate(f32::INFINITY)
-    }
-
-    fn nan() -> Self {
-        Self::replicate(f32::NAN)
-    }
-
-    fn epsilon() -> Self {
-        Self::replicate(f32::EPSILON)
-    }
-
-    fn replicate(value: f32) -> Self {
-        Self::new(value, value, value)
-    }
-
-    fn is_nan(&self) -> bool {
-        self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
-    }
-
-    fn is_infinite(&self) -> bool {
-        self.x.is_infinite() || self.y.is_infinite() || self.z.is_infinite()
-    }
-
-    fn dot(&self, other: &Self) -> f32 {
-        self.x * other.x + self.y * other.y + self.z * other.z
-    }
+/// Defines `Vector2`/`Vector3`/`Vector4` with components of type `$S`, their operators and the
+/// optional interoperability. Also used by `dvector` for the double precision types.
+macro_rules! vector_types {
+    (
+        $S:ident, $Matrix:ident, $Row:ident,
+        glam: ($G2:ident, $G3:ident, $G4:ident),
+        glium: ($A2:ident, $A3:ident, $A4:ident),
+        $(#[$attr2:meta])* struct $V2:ident;
+        $(#[$attr3:meta])* struct $V3:ident;
+        $(#[$attr4:meta])* struct $V4:ident;
+    ) => {
+        $(#[$attr2])*
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        pub struct $V2 {
+            pub x: $S,
+            pub y: $S,
+            z: $S,
+            w: $S,
+        }
 
-    fn swizzle(&self, e0: usize, e1: usize, e2: usize, _e3: usize) -> Self {
-        assert!(e0 < 4);
-        assert!(e1 < 4);
-        assert!(e2 < 4);
-        Self::new(self[e0], self[e1], self[e2])
-    }
+        $(#[$attr3])*
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        pub struct $V3 {
+            pub x: $S,
+            pub y: $S,
+            pub z: $S,
+            w: $S,
+        }
 
-    fn permute(
-        &self,
-        other: &Self,
-        permute_x: usize,
-        permute_y: usize,
-        permute_z: usize,
-        _permute_w: usize,
-    ) -> Self {
-        assert!(permute_x < 8);
-        assert!(permute_y < 8);
-        assert!(permute_z < 8);
-        let x = if permute_x < 4 {
-            self[permute_x]
-        } else {
-            other[permute_x - 4]
-        };
-        let y = if permute_y < 4 {
-            self[permute_y]
-        } else {
-            other[permute_y - 4]
-        };
-        let z = if permute_z < 4 {
-            self[permute_z]
-        } else {
-            other[permute_z - 4]
-        };
-        Self::new(x, y, z)
-    }
+        $(#[$attr4])*
+        #[derive(Clone, Copy, PartialEq, Debug)]
+        pub struct $V4 {
+            pub x: $S,
+            pub y: $S,
+            pub z: $S,
+            pub w: $S,
+        }
 
-    fn transform(&self, matrix: &Matrix) -> Self {
-        let x = self.splat_x();
-        let y = self.splat_y();
-        let z = self.splat_z();
+        impl $V2 {
+            pub const ZERO: Self = $V2::new(0.0, 0.0);
+            pub const ONE: Self = $V2::new(1.0, 1.0);
+            pub const X: Self = $V2::new(1.0, 0.0);
+            pub const Y: Self = $V2::new(0.0, 1.0);
+            pub const NEG_X: Self = $V2::new(-1.0, 0.0);
+            pub const NEG_Y: Self = $V2::new(0.0, -1.0);
+            pub const INFINITY: Self = $V2::new($S::INFINITY, $S::INFINITY);
+            pub const NAN: Self = $V2::new($S::NAN, $S::NAN);
+            pub const EPSILON: Self = $V2::new($S::EPSILON, $S::EPSILON);
+
+            pub const fn new(x: $S, y: $S) -> Self {
+                $V2 {
+                    x,
+                    y,
+                    z: 0.0,
+                    w: 0.0,
+                }
+            }
+
+            pub const fn extend(&self, z: $S) -> $V3 {
+                $V3::new(self.x, self.y, z)
+            }
+
+            /// z of the 3D cross product, the signed area of the parallelogram spanned by `self`
+            /// and `other`. Positive if `other` is counterclockwise from `self`.
+            pub fn cross(&self, other: &Self) -> $S {
+                self.x * other.y - self.y * other.x
+            }
+
+            /// `self` rotated by 90 degrees counterclockwise.
+            pub const fn perp(&self) -> Self {
+                $V2::new(-self.y, self.x)
+            }
+        }
 
-        let m0 = Self::from(matrix[0]);
-        let m1 = Self::from(matrix[1]);
-        let m2 = Self::from(matrix[2]);
-        let m3 = Self::from(matrix[3]);
+        impl $V3 {
+            pub const ZERO: Self = $V3::new(0.0, 0.0, 0.0);
+            pub const ONE: Self = $V3::new(1.0, 1.0, 1.0);
+            pub const X: Self = $V3::new(1.0, 0.0, 0.0);
+            pub const Y: Self = $V3::new(0.0, 1.0, 0.0);
+            pub const Z: Self = $V3::new(0.0, 0.0, 1.0);
+            pub const NEG_X: Self = $V3::new(-1.0, 0.0, 0.0);
+            pub const NEG_Y: Self = $V3::new(0.0, -1.0, 0.0);
+            pub const NEG_Z: Self = $V3::new(0.0, 0.0, -1.0);
+            pub const INFINITY: Self = $V3::new($S::INFINITY, $S::INFINITY, $S::INFINITY);
+            pub const NAN: Self = $V3::new($S::NAN, $S::NAN, $S::NAN);
+            pub const EPSILON: Self = $V3::new($S::EPSILON, $S::EPSILON, $S::EPSILON);
+
+            pub const fn new(x: $S, y: $S, z: $S) -> Self {
+                $V3 { x, y, z, w: 0.0 }
+            }
+
+            pub const fn extend(&self, w: $S) -> $V4 {
+                $V4::new(self.x, self.y, self.z, w)
+            }
+
+            pub const fn truncate(&self) -> $V2 {
+                $V2::new(self.x, self.y)
+            }
+
+            /// The point in homogeneous coordinates, with w = 1.
+            pub const fn to_homogeneous_point(&self) -> $V4 {
+                self.extend(1.0)
+            }
+
+            /// The direction in homogeneous coordinates, with w = 0. Translations do not move it.
+            pub const fn to_homogeneous_dir(&self) -> $V4 {
+                self.extend(0.0)
+            }
+
+            /// Divides x, y and z by w, as after a projection. Infinite or NaN if w is zero.
+            pub fn from_homogeneous(v: &$V4) -> Self {
+                v.truncate() / v.w
+            }
+
+            pub fn cross(&self, other: &Self) -> Self {
+                $V3::new(
+                    self.y * other.z - self.z * other.y,
+                    self.z * other.x - self.x * other.z,
+                    self.x * other.y - self.y * other.x,
+                )
+            }
+        }
 
-        x * m0 + y * m1 + z * m2 + m3
-    }
+        impl $V4 {
+            pub const ZERO: Self = $V4::new(0.0, 0.0, 0.0, 0.0);
+            pub const ONE: Self = $V4::new(1.0, 1.0, 1.0, 1.0);
+            pub const X: Self = $V4::new(1.0, 0.0, 0.0, 0.0);
+            pub const Y: Self = $V4::new(0.0, 1.0, 0.0, 0.0);
+            pub const Z: Self = $V4::new(0.0, 0.0, 1.0, 0.0);
+            pub const W: Self = $V4::new(0.0, 0.0, 0.0, 1.0);
+            pub const NEG_X: Self = $V4::new(-1.0, 0.0, 0.0, 0.0);
+            pub const NEG_Y: Self = $V4::new(0.0, -1.0, 0.0, 0.0);
+            pub const NEG_Z: Self = $V4::new(0.0, 0.0, -1.0, 0.0);
+            pub const NEG_W: Self = $V4::new(0.0, 0.0, 0.0, -1.0);
+            pub const INFINITY: Self =
+                $V4::new($S::INFINITY, $S::INFINITY, $S::INFINITY, $S::INFINITY);
+            pub const NAN: Self = $V4::new($S::NAN, $S::NAN, $S::NAN, $S::NAN);
+            pub const EPSILON: Self = $V4::new($S::EPSILON, $S::EPSILON, $S::EPSILON, $S::EPSILON);
+
+            pub const fn new(x: $S, y: $S, z: $S, w: $S) -> Self {
+                $V4 { x, y, z, w }
+            }
+
+            pub const fn truncate(&self) -> $V3 {
+                $V3::new(self.x, self.y, self.z)
+            }
+        }
 
-    fn min(&self, other: &Self) -> Self {
-        let x = self.x.min(other.x);
-        let y = self.y.min(other.y);
-        let z = self.z.min(other.z);
-        Self::new(x, y, z)
-    }
-    fn max(&self, other: &Self) -> Self {
-        let x = self.x.max(other.x);
-        let y = self.y.max(other.y);
-        let z = self.z.max(other.z);
-        Self::new(x, y, z)
-    }
+        impl Vector<$S> for $V2 {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            fn infinity() -> Self {
+                Self::INFINITY
+            }
+
+            fn nan() -> Self {
+                Self::NAN
+            }
+
+            fn epsilon() -> Self {
+                Self::EPSILON
+            }
+
+            fn replicate(value: $S) -> Self {
+                Self::new(value, value)
+            }
+
+            fn is_nan(&self) -> bool {
+                self.x.is_nan() || self.y.is_nan()
+            }
+
+            fn is_infinite(&self) -> bool {
+                self.x.is_infinite() || self.y.is_infinite()
+            }
+
+            fn dot(&self, other: &Self) -> $S {
+                self.x * other.x + self.y * other.y
+            }
+
+            fn swizzle(&self, e0: usize, e1: usize, _e2: usize, _e3: usize) -> Self {
+                assert!(e0 < 4);
+                assert!(e1 < 4);
+                Self::new(self[e0], self[e1])
+            }
+
+            fn permute(
+                &self,
+                other: &Self,
+                permute_x: usize,
+                permute_y: usize,
+                _permute_z: usize,
+                _permute_w: usize,
+            ) -> Self {
+                assert!(permute_x < 8);
+                assert!(permute_y < 8);
+                let x = if permute_x < 4 {
+                    self[permute_x]
+                } else {
+                    other[permute_x - 4]
+                };
+                let y = if permute_y < 4 {
+                    self[permute_y]
+                } else {
+                    other[permute_y - 4]
+                };
+                Self::new(x, y)
+            }
+
+            fn transform(&self, matrix: &$Matrix) -> Self {
+                let x = self.splat_x();
+                let y = self.splat_y();
+
+                let m0 = Self::from(matrix[0]);
+                let m1 = Self::from(matrix[1]);
+                let m3 = Self::from(matrix[3]);
+
+                x * m0 + y * m1 + m3
+            }
+
+            fn min(&self, other: &Self) -> Self {
+                let x = self.x.min(other.x);
+                let y = self.y.min(other.y);
+                Self::new(x, y)
+            }
+            fn max(&self, other: &Self) -> Self {
+                let x = self.x.max(other.x);
+                let y = self.y.max(other.y);
+                Self::new(x, y)
+            }
+
+            fn round(&self) -> Self {
+                let x = math::round(self.x);
+                let y = math::round(self.y);
+                Self::new(x, y)
+            }
+            fn trunc(&self) -> Self {
+                let x = math::trunc(self.x);
+                let y = math::trunc(self.y);
+                Self::new(x, y)
+            }
+            fn floor(&self) -> Self {
+                let x = math::floor(self.x);
+                let y = math::floor(self.y);
+                Self::new(x, y)
+            }
+            fn ceil(&self) -> Self {
+                let x = math::ceil(self.x);
+                let y = math::ceil(self.y);
+                Self::new(x, y)
+            }
+            fn clamp(&self, min: &Self, max: &Self) -> Self {
+                assert!(min.x < max.x);
+                assert!(min.y < max.y);
+                self.max(min).min(max)
+            }
+
+            fn multiply_add(&self, mul: &Self, add: &Self) -> Self {
+                *self * *mul + *add
+            }
+
+            fn map(&self, f: impl Fn($S) -> $S) -> Self {
+                Self::new(f(self.x), f(self.y))
+            }
+
+            fn zip_map(&self, other: &Self, f: impl Fn($S, $S) -> $S) -> Self {
+                Self::new(f(self.x, other.x), f(self.y, other.y))
+            }
+
+            fn splat_x(&self) -> Self {
+                Self::replicate(self.x)
+            }
+            fn splat_y(&self) -> Self {
+                Self::replicate(self.y)
+            }
+            fn splat_z(&self) -> Self {
+                Self::replicate(0.0)
+            }
+            fn splat_w(&self) -> Self {
+                Self::replicate(0.0)
+            }
+        }
 
-    fn round(&self) -> Self {
-        let x = self.x.round();
-        let y = self.y.round();
-        let z = self.z.round();
-        Self::new(x, y, z)
-    }
-    fn trunc(&self) -> Self {
-        let x = self.x.trunc();
-        let y = self.y.trunc();
-        let z = self.z.trunc();
-        Self::new(x, y, z)
-    }
-    fn floor(&self) -> Self {
-        let x = self.x.floor();
-        let y = self.y.floor();
-        let z = self.z.floor();
-        Self::new(x, y, z)
-    }
-    fn ceil(&self) -> Self {
-        let x = self.x.ceil();
-        let y = self.y.ceil();
-        let z = self.z.ceil();
-        Self::new(x, y, z)
-    }
-    fn clamp(&self, min: &Self, max: &Self) -> Self {
-        assert!(min.x < max.x);
-        assert!(min.y < max.y);
-        assert!(min.z < max.z);
-        self.max(min).min(max)
-    }
+        impl Vector<$S> for $V3 {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            fn infinity() -> Self {
+                Self::INFINITY
+            }
+
+            fn nan() -> Self {
+                Self::NAN
+            }
+
+            fn epsilon() -> Self {
+                Self::EPSILON
+            }
+
+            fn replicate(value: $S) -> Self {
+                Self::new(value, value, value)
+            }
+
+            fn is_nan(&self) -> bool {
+                self.x.is_nan() || self.y.is_nan() || self.z.is_nan()
+            }
+
+            fn is_infinite(&self) -> bool {
+                self.x.is_infinite() || self.y.is_infinite() || self.z.is_infinite()
+            }
+
+            fn dot(&self, other: &Self) -> $S {
+                self.x * other.x + self.y * other.y + self.z * other.z
+            }
+
+            fn swizzle(&self, e0: usize, e1: usize, e2: usize, _e3: usize) -> Self {
+                assert!(e0 < 4);
+                assert!(e1 < 4);
+                assert!(e2 < 4);
+                Self::new(self[e0], self[e1], self[e2])
+            }
+
+            fn permute(
+                &self,
+                other: &Self,
+                permute_x: usize,
+                permute_y: usize,
+                permute_z: usize,
+                _permute_w: usize,
+            ) -> Self {
+                assert!(permute_x < 8);
+                assert!(permute_y < 8);
+                assert!(permute_z < 8);
+                let x = if permute_x < 4 {
+                    self[permute_x]
+                } else {
+                    other[permute_x - 4]
+                };
+                let y = if permute_y < 4 {
+                    self[permute_y]
+                } else {
+                    other[permute_y - 4]
+                };
+                let z = if permute_z < 4 {
+                    self[permute_z]
+                } else {
+                    other[permute_z - 4]
+                };
+                Self::new(x, y, z)
+            }
+
+            fn transform(&self, matrix: &$Matrix) -> Self {
+                let x = self.splat_x();
+                let y = self.splat_y();
+                let z = self.splat_z();
+
+                let m0 = Self::from(matrix[0]);
+                let m1 = Self::from(matrix[1]);
+                let m2 = Self::from(matrix[2]);
+                let m3 = Self::from(matrix[3]);
+
+                x * m0 + y * m1 + z * m2 + m3
+            }
+
+            fn min(&self, other: &Self) -> Self {
+                let x = self.x.min(other.x);
+                let y = self.y.min(other.y);
+                let z = self.z.min(other.z);
+                Self::new(x, y, z)
+            }
+            fn max(&self, other: &Self) -> Self {
+                let x = self.x.max(other.x);
+                let y = self.y.max(other.y);
+                let z = self.z.max(other.z);
+                Self::new(x, y, z)
+            }
+
+            fn round(&self) -> Self {
+                let x = math::round(self.x);
+                let y = math::round(self.y);
+                let z = math::round(self.z);
+                Self::new(x, y, z)
+            }
+            fn trunc(&self) -> Self {
+                let x = math::trunc(self.x);
+                let y = math::trunc(self.y);
+                let z = math::trunc(self.z);
+                Self::new(x, y, z)
+            }
+            fn floor(&self) -> Self {
+                let x = math::floor(self.x);
+                let y = math::floor(self.y);
+                let z = math::floor(self.z);
+                Self::new(x, y, z)
+            }
+            fn ceil(&self) -> Self {
+                let x = math::ceil(self.x);
+                let y = math::ceil(self.y);
+                let z = math::ceil(self.z);
+                Self::new(x, y, z)
+            }
+            fn clamp(&self, min: &Self, max: &Self) -> Self {
+                assert!(min.x < max.x);
+                assert!(min.y < max.y);
+                assert!(min.z < max.z);
+                self.max(min).min(max)
+            }
+
+            fn multiply_add(&self, mul: &Self, add: &Self) -> Self {
+                *self * *mul + *add
+            }
+
+            fn map(&self, f: impl Fn($S) -> $S) -> Self {
+                Self::new(f(self.x), f(self.y), f(self.z))
+            }
+
+            fn zip_map(&self, other: &Self, f: impl Fn($S, $S) -> $S) -> Self {
+                Self::new(f(self.x, other.x), f(self.y, other.y), f(self.z, other.z))
+            }
+
+            fn splat_x(&self) -> Self {
+                Self::replicate(self.x)
+            }
+            fn splat_y(&self) -> Self {
+                Self::replicate(self.y)
+            }
+            fn splat_z(&self) -> Self {
+                Self::replicate(self.z)
+            }
+            fn splat_w(&self) -> Self {
+                Self::replicate(0.0)
+            }
+        }
 
-    fn multiply_add(&self, mul: &Self, add: &Self) -> Self {
-        *self * *mul + *add
-    }
+        impl Vector<$S> for $V4 {
+            fn zero() -> Self {
+                Self::ZERO
+            }
+            fn one() -> Self {
+                Self::ONE
+            }
+
+            fn infinity() -> Self {
+                Self::INFINITY
+            }
+
+            fn nan() -> Self {
+                Self::NAN
+            }
+
+            fn epsilon() -> Self {
+                Self::EPSILON
+            }
+
+            fn replicate(value: $S) -> Self {
+                Self::new(value, value, value, value)
+            }
+
+            fn is_nan(&self) -> bool {
+                self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
+            }
+
+            fn is_infinite(&self) -> bool {
+                self.x.is_infinite()
+                    || self.y.is_infinite()
+                    || self.z.is_infinite()
+                    || self.w.is_infinite()
+            }
+
+            fn dot(&self, other: &Self) -> $S {
+                self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+            }
+
+            fn swizzle(&self, e0: usize, e1: usize, e2: usize, e3: usize) -> Self {
+                assert!(e0 < 4);
+                assert!(e1 < 4);
+                assert!(e2 < 4);
+                assert!(e3 < 4);
+                Self::new(self[e0], self[e1], self[e2], self[e3])
+            }
+
+            fn permute(
+                &self,
+                other: &Self,
+                permute_x: usize,
+                permute_y: usize,
+                permute_z: usize,
+                permute_w: usize,
+            ) -> Self {
+                assert!(permute_x < 8);
+                assert!(permute_y < 8);
+                assert!(permute_z < 8);
+                assert!(permute_w < 8);
+                let x = if permute_x < 4 {
+                    self[permute_x]
+                } else {
+                    other[permute_x - 4]
+                };
+                let y = if permute_y < 4 {
+                    self[permute_y]
+                } else {
+                    other[permute_y - 4]
+                };
+                let z = if permute_z < 4 {
+                    self[permute_z]
+                } else {
+                    other[permute_z - 4]
+                };
+                let w = if permute_w < 4 {
+                    self[permute_w]
+                } else {
+                    other[permute_w - 4]
+                };
+                Self::new(x, y, z, w)
+            }
+
+            fn transform(&self, matrix: &$Matrix) -> Self {
+                let x = self.splat_x();
+                let y = self.splat_y();
+                let z = self.splat_z();
+                let w = self.splat_w();
+
+                let m0 = Self::from(matrix[0]);
+                let m1 = Self::from(matrix[1]);
+                let m2 = Self::from(matrix[2]);
+                let m3 = Self::from(matrix[3]);
+
+                x * m0 + y * m1 + z * m2 + w * m3
+            }
+
+            fn min(&self, other: &Self) -> Self {
+                let x = self.x.min(other.x);
+                let y = self.y.min(other.y);
+                let z = self.z.min(other.z);
+                let w = self.w.min(other.w);
+                Self::new(x, y, z, w)
+            }
+            fn max(&self, other: &Self) -> Self {
+                let x = self.x.max(other.x);
+                let y = self.y.max(other.y);
+                let z = self.z.max(other.z);
+                let w = self.w.max(other.w);
+                Self::new(x, y, z, w)
+            }
+
+            fn round(&self) -> Self {
+                let x = math::round(self.x);
+                let y = math::round(self.y);
+                let z = math::round(self.z);
+                let w = math::round(self.w);
+                Self::new(x, y, z, w)
+            }
+            fn trunc(&self) -> Self {
+                let x = math::trunc(self.x);
+                let y = math::trunc(self.y);
+                let z = math::trunc(self.z);
+                let w = math::trunc(self.w);
+                Self::new(x, y, z, w)
+            }
+            fn floor(&self) -> Self {
+                let x = math::floor(self.x);
+                let y = math::floor(self.y);
+                let z = math::floor(self.z);
+                let w = math::floor(self.w);
+                Self::new(x, y, z, w)
+            }
+            fn ceil(&self) -> Self {
+                let x = math::ceil(self.x);
+                let y = math::ceil(self.y);
+                let z = math::ceil(self.z);
+                let w = math::ceil(self.w);
+                Self::new(x, y, z, w)
+            }
+            fn clamp(&self, min: &Self, max: &Self) -> Self {
+                assert!(min.x < max.x);
+                assert!(min.y < max.y);
+                assert!(min.z < max.z);
+                assert!(min.w < max.w);
+                self.max(min).min(max)
+            }
+
+            fn multiply_add(&self, mul: &Self, add: &Self) -> Self {
+                *self * *mul + *add
+            }
+
+            fn map(&self, f: impl Fn($S) -> $S) -> Self {
+                Self::new(f(self.x), f(self.y), f(self.z), f(self.w))
+            }
+
+            fn zip_map(&self, other: &Self, f: impl Fn($S, $S) -> $S) -> Self {
+                Self::new(
+                    f(self.x, other.x),
+                    f(self.y, other.y),
+                    f(self.z, other.z),
+                    f(self.w, other.w),
+                )
+            }
+
+            fn splat_x(&self) -> Self {
+                Self::replicate(self.x)
+            }
+            fn splat_y(&self) -> Self {
+                Self::replicate(self.y)
+            }
+            fn splat_z(&self) -> Self {
+                Self::replicate(self.z)
+            }
+            fn splat_w(&self) -> Self {
+                Self::replicate(self.w)
+            }
+        }
 
-    fn splat_x(&self) -> Self {
-        Self::replicate(self.x)
-    }
-    fn splat_y(&self) -> Self {
-        Self::replicate(self.y)
-    }
-    fn splat_z(&self) -> Self {
-        Self::replicate(self.z)
-    }
-    fn splat_w(&self) -> Self {
-        Self::replicate(0.0)
-    }
-}
+        //
+        // Operator overloadings
+        //
+
+        impl Add for $V2 {
+            type Output = Self;
+            fn add(self, rhs: $V2) -> Self::Output {
+                let x = self.x + rhs.x;
+                let y = self.y + rhs.y;
+                Self::new(x, y)
+            }
+        }
+        impl Add for $V3 {
+            type Output = Self;
+            fn add(self, rhs: $V3) -> Self::Output {
+                let x = self.x + rhs.x;
+                let y = self.y + rhs.y;
+                let z = self.z + rhs.z;
+                Self::new(x, y, z)
+            }
+        }
+        impl Add for $V4 {
+            type Output = Self;
+            fn add(self, rhs: $V4) -> Self::Output {
+                let x = self.x + rhs.x;
+                let y = self.y + rhs.y;
+                let z = self.z + rhs.z;
+                let w = self.w + rhs.w;
+                Self::new(x, y, z, w)
+            }
+        }
 
-impl Vector for Vector4 {
-    fn zero() -> Self {
-        Self::replicate(0.0)
-    }
-    fn one() -> Self {
-        Self::replicate(1.0)
-    }
+        impl AddAssign for $V2 {
+            fn add_assign(&mut self, rhs: Self) {
+                self.x += rhs.x;
+                self.y += rhs.y;
+            }
+        }
+        impl AddAssign for $V3 {
+            fn add_assign(&mut self, rhs: Self) {
+                self.x += rhs.x;
+                self.y += rhs.y;
+                self.z += rhs.z;
+            }
+        }
+        impl AddAssign for $V4 {
+            fn add_assign(&mut self, rhs: Self) {
+                self.x += rhs.x;
+                self.y += rhs.y;
+                self.z += rhs.z;
+                self.w += rhs.w;
+            }
+        }
 
-    fn infinity() -> Self {
-        Self::replicate(f32::INFINITY)
-    }
+        impl Sub for $V2 {
+            type Output = Self;
+            fn sub(self, rhs: $V2) -> Self::Output {
+                let x = self.x - rhs.x;
+                let y = self.y - rhs.y;
+                Self::new(x, y)
+            }
+        }
+        impl Sub for $V3 {
+            type Output = Self;
+            fn sub(self, rhs: $V3) -> Self::Output {
+                let x = self.x - rhs.x;
+                let y = self.y - rhs.y;
+                let z = self.z - rhs.z;
+                Self::new(x, y, z)
+            }
+        }
+        impl Sub for $V4 {
+            type Output = Self;
+            fn sub(self, rhs: $V4) -> Self::Output {
+                let x = self.x - rhs.x;
+                let y = self.y - rhs.y;
+                let z = self.z - rhs.z;
+                let w = self.w - rhs.w;
+                Self::new(x, y, z, w)
+            }
+        }
 
-    fn nan() -> Self {
-        Self::replicate(f32::NAN)
-    }
+        impl SubAssign for $V2 {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.x -= rhs.x;
+                self.y -= rhs.y;
+            }
+        }
+        impl SubAssign for $V3 {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.x -= rhs.x;
+                self.y -= rhs.y;
+                self.z -= rhs.z;
+            }
+        }
+        impl SubAssign for $V4 {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.x -= rhs.x;
+                self.y -= rhs.y;
+                self.z -= rhs.z;
+                self.w -= rhs.w;
+            }
+        }
 
-    fn epsilon() -> Self {
-        Self::replicate(f32::EPSILON)
-    }
+        impl Div for $V2 {
+            type Output = Self;
+            fn div(self, rhs: $V2) -> Self::Output {
+                let x = self.x / rhs.x;
+                let y = self.y / rhs.y;
+                Self::new(x, y)
+            }
+        }
+        impl Div for $V3 {
+            type Output = Self;
+            fn div(self, rhs: $V3) -> Self::Output {
+                let x = self.x / rhs.x;
+                let y = self.y / rhs.y;
+                let z = self.z / rhs.z;
+                Self::new(x, y, z)
+            }
+        }
+        impl Div for $V4 {
+            type Output = Self;
+            fn div(self, rhs: $V4) -> Self::Output {
+                let x = self.x / rhs.x;
+                let y = self.y / rhs.y;
+                let z = self.z / rhs.z;
+                let w = self.w / rhs.w;
+                Self::new(x, y, z, w)
+            }
+        }
 
-    fn replicate(value: f32) -> Self {
-        Self::new(value, value, value, value)
-    }
+        impl DivAssign for $V2 {
+            fn div_assign(&mut self, rhs: Self) {
+                self.x /= rhs.x;
+                self.y /= rhs.y;
+            }
+        }
+        impl DivAssign for $V3 {
+            fn div_assign(&mut self, rhs: Self) {
+                self.x /= rhs.x;
+                self.y /= rhs.y;
+                self.z /= rhs.z;
+            }
+        }
+        impl DivAssign for $V4 {
+            fn div_assign(&mut self, rhs: Self) {
+                self.x /= rhs.x;
+                self.y /= rhs.y;
+                self.z /= rhs.z;
+                self.w /= rhs.w;
+            }
+        }
 
-    fn is_nan(&self) -> bool {
-        self.x.is_nan() || self.y.is_nan() || self.z.is_nan() || self.w.is_nan()
-    }
+        impl Div<$S> for $V2 {
+            type Output = Self;
+            fn div(self, rhs: $S) -> Self::Output {
+                let x = self.x / rhs;
+                let y = self.y / rhs;
+                Self::new(x, y)
+            }
+        }
+        impl Div<$S> for $V3 {
+            type Output = Self;
+            fn div(self, rhs: $S) -> Self::Output {
+                let x = self.x / rhs;
+                let y = self.y / rhs;
+                let z = self.z / rhs;
+                Self::new(x, y, z)
+            }
+        }
+        impl Div<$S> for $V4 {
+            type Output = Self;
+            fn div(self, rhs: $S) -> Self::Output {
+                let x = self.x / rhs;
+                let y = self.y / rhs;
+                let z = self.z / rhs;
+                let w = self.w / rhs;
+                Self::new(x, y, z, w)
+            }
+        }
 
-    fn is_infinite(&self) -> bool {
-        self.x.is_infinite() || self.y.is_infinite() || self.z.is_infinite() || self.w.is_infinite()
-    }
+        impl DivAssign<$S> for $V2 {
+            fn div_assign(&mut self, rhs: $S) {
+                self.x /= rhs;
+                self.y /= rhs;
+            }
+        }
+        impl DivAssign<$S> for $V3 {
+            fn div_assign(&mut self, rhs: $S) {
+                self.x /= rhs;
+                self.y /= rhs;
+                self.z /= rhs;
+            }
+        }
+        impl DivAssign<$S> for $V4 {
+            fn div_assign(&mut self, rhs: $S) {
+                self.x /= rhs;
+                self.y /= rhs;
+                self.z /= rhs;
+                self.w /= rhs;
+            }
+        }
 
-    fn dot(&self, other: &Self) -> f32 {
-        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
-    }
+        impl Mul for $V2 {
+            type Output = Self;
+            fn mul(self, rhs: $V2) -> Self::Output {
+                let x = self.x * rhs.x;
+                let y = self.y * rhs.y;
+                Self::new(x, y)
+            }
+        }
+        impl Mul for $V3 {
+            type Output = Self;
+            fn mul(self, rhs: $V3) -> Self::Output {
+                let x = self.x * rhs.x;
+                let y = self.y * rhs.y;
+                let z = self.z * rhs.z;
+                Self::new(x, y, z)
+            }
+        }
+        impl Mul for $V4 {
+            type Output = Self;
+            fn mul(self, rhs: $V4) -> Self::Output {
+                let x = self.x * rhs.x;
+                let y = self.y * rhs.y;
+                let z = self.z * rhs.z;
+                let w = self.w * rhs.w;
+                Self::new(x, y, z, w)
+            }
+        }
 
-    fn swizzle(&self, e0: usize, e1: usize, e2: usize, e3: usize) -> Self {
-        assert!(e0 < 4);
-        assert!(e1 < 4);
-        assert!(e2 < 4);
-        assert!(e3 < 4);
-        Self::new(self[e0], self[e1], self[e2], self[e3])
-    }
+        impl MulAssign for $V2 {
+            fn mul_assign(&mut self, rhs: Self) {
+                self.x *= rhs.x;
+                self.y *= rhs.y;
+            }
+        }
+        impl MulAssign for $V3 {
+            fn mul_assign(&mut self, rhs: Self) {
+                self.x *= rhs.x;
+                self.y *= rhs.y;
+                self.z *= rhs.z;
+            }
+        }
+        impl MulAssign for $V4 {
+            fn mul_assign(&mut self, rhs: Self) {
+                self.x *= rhs.x;
+                self.y *= rhs.y;
+                self.z *= rhs.z;
+                self.w *= rhs.w;
+            }
+        }
 
-    fn permute(
-        &self,
-        other: &Self,
-        permute_x: usize,
-        permute_y: usize,
-        permute_z: usize,
-        permute_w: usize,
-    ) -> Self {
-        assert!(permute_x < 8);
-        assert!(permute_y < 8);
-        assert!(permute_z < 8);
-        assert!(permute_w < 8);
-        let x = if permute_x < 4 {
-            self[permute_x]
-        } else {
-            other[permute_x - 4]
-        };
-        let y = if permute_y < 4 {
-            self[permute_y]
-        } else {
-            other[permute_y - 4]
-        };
-        let z = if permute_z < 4 {
-            self[permute_z]
-        } else {
-            other[permute_z - 4]
-        };
-        let w = if permute_w < 4 {
-            self[permute_w]
-        } else {
-            other[permute_w - 4]
-        };
-        Self::new(x, y, z, w)
-    }
+        impl Mul<$S> for $V2 {
+            type Output = Self;
+            fn mul(self, rhs: $S) -> Self::Output {
+                let x = self.x * rhs;
+                let y = self.y * rhs;
+                Self::new(x, y)
+            }
+        }
+        impl Mul<$S> for $V3 {
+            type Output = Self;
+            fn mul(self, rhs: $S) -> Self::Output {
+                let x = self.x * rhs;
+                let y = self.y * rhs;
+                let z = self.z * rhs;
+                Self::new(x, y, z)
+            }
+        }
+        impl Mul<$S> for $V4 {
+            type Output = Self;
+            fn mul(self, rhs: $S) -> Self::Output {
+                let x = self.x * rhs;
+                let y = self.y * rhs;
+                let z = self.z * rhs;
+                let w = self.w * rhs;
+                Self::new(x, y, z, w)
+            }
+        }
 
-    fn transform(&self, matrix: &Matrix) -> Self {
-        let x = self.splat_x();
-        let y = self.splat_y();
-        let z = self.splat_z();
-        let w = self.splat_w();
+        impl MulAssign<$S> for $V2 {
+            fn mul_assign(&mut self, rhs: $S) {
+                self.x *= rhs;
+                self.y *= rhs;
+            }
+        }
+        impl MulAssign<$S> for $V3 {
+            fn mul_assign(&mut self, rhs: $S) {
+                self.x *= rhs;
+                self.y *= rhs;
+                self.z *= rhs;
+            }
+        }
+        impl MulAssign<$S> for $V4 {
+            fn mul_assign(&mut self, rhs: $S) {
+                self.x *= rhs;
+                self.y *= rhs;
+                self.z *= rhs;
+                self.w *= rhs;
+            }
+        }
 
-        let m0 = Self::from(matrix[0]);
-        let m1 = Self::from(matrix[1]);
-        let m2 = Self::from(matrix[2]);
-        let m3 = Self::from(matrix[3]);
+        impl Mul<$V2> for $S {
+            type Output = $V2;
+            fn mul(self, rhs: $V2) -> Self::Output {
+                let x = self * rhs.x;
+                let y = self * rhs.y;
+                Self::Output::new(x, y)
+            }
+        }
+        impl Mul<$V3> for $S {
+            type Output = $V3;
+            fn mul(self, rhs: $V3) -> Self::Output {
+                let x = self * rhs.x;
+                let y = self * rhs.y;
+                let z = self * rhs.z;
+                Self::Output::new(x, y, z)
+            }
+        }
+        impl Mul<$V4> for $S {
+            type Output = $V4;
+            fn mul(self, rhs: $V4) -> Self::Output {
+                let x = self * rhs.x;
+                let y = self * rhs.y;
+                let z = self * rhs.z;
+                let w = self * rhs.w;
+                Self::Output::new(x, y, z, w)
+            }
+        }
 
-        x * m0 + y * m1 + z * m2 + w * m3
-    }
+        impl Neg for $V2 {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                let x = -self.x;
+                let y = -self.y;
+                Self::new(x, y)
+            }
+        }
+        impl Neg for $V3 {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                let x = -self.x;
+                let y = -self.y;
+                let z = -self.z;
+                Self::new(x, y, z)
+            }
+        }
+        impl Neg for $V4 {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                let x = -self.x;
+                let y = -self.y;
+                let z = -self.z;
+                let w = -self.w;
+                Self::new(x, y, z, w)
+            }
+        }
 
-    fn min(&self, other: &Self) -> Self {
-        let x = self.x.min(other.x);
-        let y = self.y.min(other.y);
-        let z = self.z.min(other.z);
-        let w = self.w.min(other.w);
-        Self::new(x, y, z, w)
-    }
-    fn max(&self, other: &Self) -> Self {
-        let x = self.x.max(other.x);
-        let y = self.y.max(other.y);
-        let z = self.z.max(other.z);
-        let w = self.w.max(other.w);
-        Self::new(x, y, z, w)
-    }
+        impl Index<usize> for $V2 {
+            type Output = $S;
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    0 => &self.x,
+                    1 => &self.y,
+                    2 => &0.0,
+                    3 => &0.0,
+                    _ => panic!("index must be between 0~3, but {}", index),
+                }
+            }
+        }
+        impl Index<usize> for $V3 {
+            type Output = $S;
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    0 => &self.x,
+                    1 => &self.y,
+                    2 => &self.z,
+                    3 => &0.0,
+                    _ => panic!("index must be between 0~3, but {}", index),
+                }
+            }
+        }
+        impl Index<usize> for $V4 {
+            type Output = $S;
+            fn index(&self, index: usize) -> &Self::Output {
+                match index {
+                    0 => &self.x,
+                    1 => &self.y,
+                    2 => &self.z,
+                    3 => &self.w,
+                    _ => panic!("index must be between 0~3, but {}", index),
+                }
+            }
+        }
 
-    fn round(&self) -> Self {
-        let x = self.x.round();
-        let y = self.y.round();
-        let z = self.z.round();
-        let w = self.w.round();
-        Self::new(x, y, z, w)
-    }
-    fn trunc(&self) -> Self {
-        let x = self.x.trunc();
-        let y = self.y.trunc();
-        let z = self.z.trunc();
-        let w = self.w.trunc();
-        Self::new(x, y, z, w)
-    }
-    fn floor(&self) -> Self {
-        let x = self.x.floor();
-        let y = self.y.floor();
-        let z = self.z.floor();
-        let w = self.w.floor();
-        Self::new(x, y, z, w)
-    }
-    fn ceil(&self) -> Self {
-        let x = self.x.ceil();
-        let y = self.y.ceil();
-        let z = self.z.ceil();
-        let w = self.w.ceil();
-        Self::new(x, y, z, w)
-    }
-    fn clamp(&self, min: &Self, max: &Self) -> Self {
-        assert!(min.x < max.x);
-        assert!(min.y < max.y);
-        assert!(min.z < max.z);
-        assert!(min.w < max.w);
-        self.max(min).min(max)
-    }
+        impl From<$Row> for $V2 {
+            fn from(row: $Row) -> Self {
+                Self::new(row[0], row[1])
+            }
+        }
 
-    fn multiply_add(&self, mul: &Self, add: &Self) -> Self {
-        *self * *mul + *add
-    }
+        impl From<$Row> for $V3 {
+            fn from(row: $Row) -> Self {
+                Self::new(row[0], row[1], row[2])
+            }
+        }
 
-    fn splat_x(&self) -> Self {
-        Self::replicate(self.x)
-    }
-    fn splat_y(&self) -> Self {
-        Self::replicate(self.y)
-    }
-    fn splat_z(&self) -> Self {
-        Self::replicate(self.z)
-    }
-    fn splat_w(&self) -> Self {
-        Self::replicate(self.w)
-    }
-}
+        impl From<$Row> for $V4 {
+            fn from(row: $Row) -> Self {
+                Self::new(row[0], row[1], row[2], row[3])
+            }
+        }
 
-//
-// Operator overloadings
-//
+        impl From<[$S; 2]> for $V2 {
+            fn from(a: [$S; 2]) -> Self {
+                Self::new(a[0], a[1])
+            }
+        }
 
-impl Add for Vector2 {
-    type Output = Self;
-    fn add(self, rhs: Vector2) -> Self::Output {
-        let x = self.x + rhs.x;
-        let y = self.y + rhs.y;
-        Self::new(x, y)
-    }
-}
-impl Add for Vector3 {
-    type Output = Self;
-    fn add(self, rhs: Vector3) -> Self::Output {
-        let x = self.x + rhs.x;
-        let y = self.y + rhs.y;
-        let z = self.z + rhs.z;
-        Self::new(x, y, z)
-    }
-}
-impl Add for Vector4 {
-    type Output = Self;
-    fn add(self, rhs: Vector4) -> Self::Output {
-        let x = self.x + rhs.x;
-        let y = self.y + rhs.y;
-        let z = self.z + rhs.z;
-        let w = self.w + rhs.w;
-        Self::new(x, y, z, w)
-    }
-}
+        impl From<$V2> for [$S; 2] {
+            fn from(v: $V2) -> Self {
+                [v.x, v.y]
+            }
+        }
 
-impl AddAssign for Vector2 {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-    }
-}
-impl AddAssign for Vector3 {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-    }
-}
-impl AddAssign for Vector4 {
-    fn add_assign(&mut self, rhs: Self) {
-        self.x += rhs.x;
-        self.y += rhs.y;
-        self.z += rhs.z;
-        self.w += rhs.w;
-    }
-}
+        impl From<($S, $S)> for $V2 {
+            fn from((x, y): ($S, $S)) -> Self {
+                Self::new(x, y)
+            }
+        }
 
-impl Sub for Vector2 {
-    type Output = Self;
-    fn sub(self, rhs: Vector2) -> Self::Output {
-        let x = self.x - rhs.x;
-        let y = self.y - rhs.y;
-        Self::new(x, y)
-    }
-}
-impl Sub for Vector3 {
-    type Output = Self;
-    fn sub(self, rhs: Vector3) -> Self::Output {
-        let x = self.x - rhs.x;
-        let y = self.y - rhs.y;
-        let z = self.z - rhs.z;
-        Self::new(x, y, z)
-    }
-}
-impl Sub for Vector4 {
-    type Output = Self;
-    fn sub(self, rhs: Vector4) -> Self::Output {
-        let x = self.x - rhs.x;
-        let y = self.y - rhs.y;
-        let z = self.z - rhs.z;
-        let w = self.w - rhs.w;
-        Self::new(x, y, z, w)
-    }
-}
+        impl From<$V2> for ($S, $S) {
+            fn from(v: $V2) -> Self {
+                (v.x, v.y)
+            }
+        }
 
-impl SubAssign for Vector2 {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-    }
-}
-impl SubAssign for Vector3 {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
-    }
-}
-impl SubAssign for Vector4 {
-    fn sub_assign(&mut self, rhs: Self) {
-        self.x -= rhs.x;
-        self.y -= rhs.y;
-        self.z -= rhs.z;
-        self.w -= rhs.w;
-    }
-}
+        impl From<[$S; 3]> for $V3 {
+            fn from(a: [$S; 3]) -> Self {
+                Self::new(a[0], a[1], a[2])
+            }
+        }
 
-impl Div for Vector2 {
-    type Output = Self;
-    fn div(self, rhs: Vector2) -> Self::Output {
-        let x = self.x / rhs.x;
-        let y = self.y / rhs.y;
-        Self::new(x, y)
-    }
-}
-impl Div for Vector3 {
-    type Output = Self;
-    fn div(self, rhs: Vector3) -> Self::Output {
-        let x = self.x / rhs.x;
-        let y = self.y / rhs.y;
-        let z = self.z / rhs.z;
-        Self::new(x, y, z)
-    }
-}
-impl Div for Vector4 {
-    type Output = Self;
-    fn div(self, rhs: Vector4) -> Self::Output {
-        let x = self.x / rhs.x;
-        let y = self.y / rhs.y;
-        let z = self.z / rhs.z;
-        let w = self.w / rhs.w;
-        Self::new(x, y, z, w)
-    }
-}
+        impl From<$V3> for [$S; 3] {
+            fn from(v: $V3) -> Self {
+                [v.x, v.y, v.z]
+            }
+        }
 
-impl DivAssign for Vector2 {
-    fn div_assign(&mut self, rhs: Self) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-    }
-}
-impl DivAssign for Vector3 {
-    fn div_assign(&mut self, rhs: Self) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-        self.z /= rhs.z;
-    }
-}
-impl DivAssign for Vector4 {
-    fn div_assign(&mut self, rhs: Self) {
-        self.x /= rhs.x;
-        self.y /= rhs.y;
-        self.z /= rhs.z;
-        self.w /= rhs.w;
-    }
-}
+        impl From<($S, $S, $S)> for $V3 {
+            fn from((x, y, z): ($S, $S, $S)) -> Self {
+                Self::new(x, y, z)
+            }
+        }
 
-impl Div<f32> for Vector2 {
-    type Output = Self;
-    fn div(self, rhs: f32) -> Self::Output {
-        let x = self.x / rhs;
-        let y = self.y / rhs;
-        Self::new(x, y)
-    }
-}
-impl Div<f32> for Vector3 {
-    type Output = Self;
-    fn div(self, rhs: f32) -> Self::Output {
-        let x = self.x / rhs;
-        let y = self.y / rhs;
-        let z = self.z / rhs;
-        Self::new(x, y, z)
-    }
-}
-impl Div<f32> for Vector4 {
-    type Output = Self;
-    fn div(self, rhs: f32) -> Self::Output {
-        let x = self.x / rhs;
-        let y = self.y / rhs;
-        let z = self.z / rhs;
-        let w = self.w / rhs;
-        Self::new(x, y, z, w)
-    }
-}
+        impl From<$V3> for ($S, $S, $S) {
+            fn from(v: $V3) -> Self {
+                (v.x, v.y, v.z)
+            }
+        }
 
-impl DivAssign<f32> for Vector2 {
-    fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-    }
-}
-impl DivAssign<f32> for Vector3 {
-    fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
-    }
-}
-impl DivAssign<f32> for Vector4 {
-    fn div_assign(&mut self, rhs: f32) {
-        self.x /= rhs;
-        self.y /= rhs;
-        self.z /= rhs;
-        self.w /= rhs;
-    }
-}
+        impl From<$V4> for [$S; 4] {
+            fn from(v: $V4) -> Self {
+                [v.x, v.y, v.z, v.w]
+            }
+        }
 
-impl Mul for Vector2 {
-    type Output = Self;
-    fn mul(self, rhs: Vector2) -> Self::Output {
-        let x = self.x * rhs.x;
-        let y = self.y * rhs.y;
-        Self::new(x, y)
-    }
-}
-impl Mul for Vector3 {
-    type Output = Self;
-    fn mul(self, rhs: Vector3) -> Self::Output {
-        let x = self.x * rhs.x;
-        let y = self.y * rhs.y;
-        let z = self.z * rhs.z;
-        Self::new(x, y, z)
-    }
-}
-impl Mul for Vector4 {
-    type Output = Self;
-    fn mul(self, rhs: Vector4) -> Self::Output {
-        let x = self.x * rhs.x;
-        let y = self.y * rhs.y;
-        let z = self.z * rhs.z;
-        let w = self.w * rhs.w;
-        Self::new(x, y, z, w)
-    }
-}
+        impl From<($S, $S, $S, $S)> for $V4 {
+            fn from((x, y, z, w): ($S, $S, $S, $S)) -> Self {
+                Self::new(x, y, z, w)
+            }
+        }
 
-impl MulAssign for Vector2 {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-    }
-}
-impl MulAssign for Vector3 {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-        self.z *= rhs.z;
-    }
-}
-impl MulAssign for Vector4 {
-    fn mul_assign(&mut self, rhs: Self) {
-        self.x *= rhs.x;
-        self.y *= rhs.y;
-        self.z *= rhs.z;
-        self.w *= rhs.w;
-    }
-}
+        impl From<$V4> for ($S, $S, $S, $S) {
+            fn from(v: $V4) -> Self {
+                (v.x, v.y, v.z, v.w)
+            }
+        }
 
-impl Mul<f32> for Vector2 {
-    type Output = Self;
-    fn mul(self, rhs: f32) -> Self::Output {
-        let x = self.x * rhs;
-        let y = self.y * rhs;
-        Self::new(x, y)
-    }
-}
-impl Mul<f32> for Vector3 {
-    type Output = Self;
-    fn mul(self, rhs: f32) -> Self::Output {
-        let x = self.x * rhs;
-        let y = self.y * rhs;
-        let z = self.z * rhs;
-        Self::new(x, y, z)
-    }
-}
-impl Mul<f32> for Vector4 {
-    type Output = Self;
-    fn mul(self, rhs: f32) -> Self::Output {
-        let x = self.x * rhs;
-        let y = self.y * rhs;
-        let z = self.z * rhs;
-        let w = self.w * rhs;
-        Self::new(x, y, z, w)
-    }
-}
+        #[cfg(feature = "serde")]
+        mod serde_support {
+            use super::{$V2, $V3, $V4};
+            use core::fmt;
+            use serde::de::{Error, IgnoredAny, SeqAccess, Visitor};
+            use serde::ser::SerializeTuple;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            $crate::vector::serde_vector!($V2, 2, "an array of 2 floats", x, y);
+            $crate::vector::serde_vector!($V3, 3, "an array of 3 floats", x, y, z);
+            $crate::vector::serde_vector!($V4, 4, "an array of 4 floats", x, y, z, w);
+        }
 
-impl MulAssign<f32> for Vector2 {
-    fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-    }
-}
-impl MulAssign<f32> for Vector3 {
-    fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
-    }
-}
-impl MulAssign<f32> for Vector4 {
-    fn mul_assign(&mut self, rhs: f32) {
-        self.x *= rhs;
-        self.y *= rhs;
-        self.z *= rhs;
-        self.w *= rhs;
-    }
-}
+        #[cfg(feature = "bytemuck")]
+        mod bytemuck_support {
+            use super::{$V2, $V3, $V4};
+            use bytemuck::{CheckedBitPattern, NoUninit, Pod, Zeroable};
+
+            // The hidden lanes must stay zero, so `Vector2` and `Vector3` are not `Pod`. They
+            // cast to bytes freely, and from bytes through `bytemuck::checked`, which rejects
+            // nonzero hidden lanes. `Vector4` has the same layout and serves as their raw bits.
+            unsafe impl Zeroable for $V2 {}
+            unsafe impl NoUninit for $V2 {}
+            unsafe impl CheckedBitPattern for $V2 {
+                type Bits = $V4;
+                fn is_valid_bit_pattern(bits: &$V4) -> bool {
+                    bits.z == 0.0 && bits.w == 0.0
+                }
+            }
+            unsafe impl Zeroable for $V3 {}
+            unsafe impl NoUninit for $V3 {}
+            unsafe impl CheckedBitPattern for $V3 {
+                type Bits = $V4;
+                fn is_valid_bit_pattern(bits: &$V4) -> bool {
+                    bits.w == 0.0
+                }
+            }
+            unsafe impl Zeroable for $V4 {}
+            unsafe impl Pod for $V4 {}
+        }
 
-impl Mul<Vector2> for f32 {
-    type Output = Vector2;
-    fn mul(self, rhs: Vector2) -> Self::Output {
-        let x = self * rhs.x;
-        let y = self * rhs.y;
-        Self::Output::new(x, y)
-    }
-}
-impl Mul<Vector3> for f32 {
-    type Output = Vector3;
-    fn mul(self, rhs: Vector3) -> Self::Output {
-        let x = self * rhs.x;
-        let y = self * rhs.y;
-        let z = self * rhs.z;
-        Self::Output::new(x, y, z)
-    }
-}
-impl Mul<Vector4> for f32 {
-    type Output = Vector4;
-    fn mul(self, rhs: Vector4) -> Self::Output {
-        let x = self * rhs.x;
-        let y = self * rhs.y;
-        let z = self * rhs.z;
-        let w = self * rhs.w;
-        Self::Output::new(x, y, z, w)
-    }
-}
+        #[cfg(feature = "mint")]
+        mod mint_support {
+            use super::{$V2, $V3, $V4};
+
+            impl From<mint::Vector2<$S>> for $V2 {
+                fn from(v: mint::Vector2<$S>) -> Self {
+                    Self::new(v.x, v.y)
+                }
+            }
+            impl From<$V2> for mint::Vector2<$S> {
+                fn from(v: $V2) -> Self {
+                    mint::Vector2 { x: v.x, y: v.y }
+                }
+            }
+            impl mint::IntoMint for $V2 {
+                type MintType = mint::Vector2<$S>;
+            }
+
+            impl From<mint::Vector3<$S>> for $V3 {
+                fn from(v: mint::Vector3<$S>) -> Self {
+                    Self::new(v.x, v.y, v.z)
+                }
+            }
+            impl From<$V3> for mint::Vector3<$S> {
+                fn from(v: $V3) -> Self {
+                    mint::Vector3 {
+                        x: v.x,
+                        y: v.y,
+                        z: v.z,
+                    }
+                }
+            }
+            impl mint::IntoMint for $V3 {
+                type MintType = mint::Vector3<$S>;
+            }
+
+            impl From<mint::Vector4<$S>> for $V4 {
+                fn from(v: mint::Vector4<$S>) -> Self {
+                    Self::new(v.x, v.y, v.z, v.w)
+                }
+            }
+            impl From<$V4> for mint::Vector4<$S> {
+                fn from(v: $V4) -> Self {
+                    mint::Vector4 {
+                        x: v.x,
+                        y: v.y,
+                        z: v.z,
+                        w: v.w,
+                    }
+                }
+            }
+            impl mint::IntoMint for $V4 {
+                type MintType = mint::Vector4<$S>;
+            }
+        }
 
-impl Neg for Vector2 {
-    type Output = Self;
-    fn neg(self) -> Self::Output {
-        let x = -self.x;
-        let y = -self.y;
-        Self::new(x, y)
-    }
-}
-impl Neg for Vector3 {
-    type Output = Self;
-    fn neg(self) -> Self::Output {
-        let x = -self.x;
-        let y = -self.y;
-        let z = -self.z;
-        Self::new(x, y, z)
-    }
-}
-impl Neg for Vector4 {
-    type Output = Self;
-    fn neg(self) -> Self::Output {
-        let x = -self.x;
-        let y = -self.y;
-        let z = -self.z;
-        let w = -self.w;
-        Self::new(x, y, z, w)
-    }
-}
+        #[cfg(feature = "glam")]
+        mod glam_support {
+            use super::{$V2, $V3, $V4};
+
+            impl From<glam::$G2> for $V2 {
+                fn from(v: glam::$G2) -> Self {
+                    Self::new(v.x, v.y)
+                }
+            }
+            impl From<$V2> for glam::$G2 {
+                fn from(v: $V2) -> Self {
+                    glam::$G2::new(v.x, v.y)
+                }
+            }
+
+            impl From<glam::$G3> for $V3 {
+                fn from(v: glam::$G3) -> Self {
+                    Self::new(v.x, v.y, v.z)
+                }
+            }
+            impl From<$V3> for glam::$G3 {
+                fn from(v: $V3) -> Self {
+                    glam::$G3::new(v.x, v.y, v.z)
+                }
+            }
+
+            impl From<glam::$G4> for $V4 {
+                fn from(v: glam::$G4) -> Self {
+                    Self::new(v.x, v.y, v.z, v.w)
+                }
+            }
+            impl From<$V4> for glam::$G4 {
+                fn from(v: $V4) -> Self {
+                    glam::$G4::new(v.x, v.y, v.z, v.w)
+                }
+            }
+        }
 
-const ZERO: &f32 = &0.0;
+        #[cfg(feature = "nalgebra")]
+        mod nalgebra_support {
+            use super::{$V2, $V3, $V4};
+
+            impl From<nalgebra::Vector2<$S>> for $V2 {
+                fn from(v: nalgebra::Vector2<$S>) -> Self {
+                    Self::new(v.x, v.y)
+                }
+            }
+            impl From<$V2> for nalgebra::Vector2<$S> {
+                fn from(v: $V2) -> Self {
+                    nalgebra::Vector2::new(v.x, v.y)
+                }
+            }
+
+            impl From<nalgebra::Vector3<$S>> for $V3 {
+                fn from(v: nalgebra::Vector3<$S>) -> Self {
+                    Self::new(v.x, v.y, v.z)
+                }
+            }
+            impl From<$V3> for nalgebra::Vector3<$S> {
+                fn from(v: $V3) -> Self {
+                    nalgebra::Vector3::new(v.x, v.y, v.z)
+                }
+            }
+
+            impl From<nalgebra::Vector4<$S>> for $V4 {
+                fn from(v: nalgebra::Vector4<$S>) -> Self {
+                    Self::new(v.x, v.y, v.z, v.w)
+                }
+            }
+            impl From<$V4> for nalgebra::Vector4<$S> {
+                fn from(v: $V4) -> Self {
+                    nalgebra::Vector4::new(v.x, v.y, v.z, v.w)
+                }
+            }
+        }
 
-impl Index<usize> for Vector2 {
-    type Output = f32;
-    fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.x,
-            1 => &self.y,
-            2 => ZERO,
-            3 => ZERO,
-            _ => panic!("index must be between 0~3, but {}", index),
+        #[cfg(feature = "cgmath")]
+        mod cgmath_support {
+            use super::{$V2, $V3, $V4};
+
+            impl From<cgmath::Vector2<$S>> for $V2 {
+                fn from(v: cgmath::Vector2<$S>) -> Self {
+                    Self::new(v.x, v.y)
+                }
+            }
+            impl From<$V2> for cgmath::Vector2<$S> {
+                fn from(v: $V2) -> Self {
+                    cgmath::Vector2::new(v.x, v.y)
+                }
+            }
+
+            impl From<cgmath::Vector3<$S>> for $V3 {
+                fn from(v: cgmath::Vector3<$S>) -> Self {
+                    Self::new(v.x, v.y, v.z)
+                }
+            }
+            impl From<$V3> for cgmath::Vector3<$S> {
+                fn from(v: $V3) -> Self {
+                    cgmath::Vector3::new(v.x, v.y, v.z)
+                }
+            }
+
+            impl From<cgmath::Vector4<$S>> for $V4 {
+                fn from(v: cgmath::Vector4<$S>) -> Self {
+                    Self::new(v.x, v.y, v.z, v.w)
+                }
+            }
+            impl From<$V4> for cgmath::Vector4<$S> {
+                fn from(v: $V4) -> Self {
+                    cgmath::Vector4::new(v.x, v.y, v.z, v.w)
+                }
+            }
         }
-    }
-}
-impl Index<usize> for Vector3 {
-    type Output = f32;
-    fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.x,
-            1 => &self.y,
-            2 => &self.z,
-            3 => ZERO,
-            _ => panic!("index must be between 0~3, but {}", index),
+
+        #[cfg(feature = "glium-support")]
+        mod glium_support {
+            use super::{$V2, $V3, $V4};
+            use glium::vertex::{Attribute, AttributeType};
+
+            unsafe impl Attribute for $V2 {
+                fn get_type() -> AttributeType {
+                    AttributeType::$A2
+                }
+            }
+            unsafe impl Attribute for $V3 {
+                fn get_type() -> AttributeType {
+                    AttributeType::$A3
+                }
+            }
+            unsafe impl Attribute for $V4 {
+                fn get_type() -> AttributeType {
+                    AttributeType::$A4
+                }
+            }
         }
-    }
+    };
 }
-impl Index<usize> for Vector4 {
-    type Output = f32;
-    fn index(&self, index: usize) -> &Self::Output {
-        match index {
-            0 => &self.x,
-            1 => &self.y,
-            2 => &self.z,
-            3 => &self.w,
-            _ => panic!("index must be between 0~3, but {}", index),
+pub(crate) use vector_types;
+
+#[cfg(feature = "serde")]
+// Only the meaningful components are written, as a tuple of floats. Self describing formats
+// like JSON see an array, binary formats store the bare floats without a length prefix.
+macro_rules! serde_vector {
+    ($ty:ident, $len:expr, $expecting:expr, $($field:ident),+) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut tuple = serializer.serialize_tuple($len)?;
+                $(tuple.serialize_element(&self.$field)?;)+
+                tuple.end()
+            }
         }
-    }
-}
 
-impl From<Row> for Vector2 {
-    fn from(row: Row) -> Self {
-        Self::new(row[0], row[1])
-    }
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct ComponentsVisitor;
+
+                impl<'de> Visitor<'de> for ComponentsVisitor {
+                    type Value = $ty;
+
+                    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                        f.write_str($expecting)
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<$ty, A::Error> {
+                        let mut len = 0;
+                        $(
+                            let $field = seq
+                                .next_element()?
+                                .ok_or_else(|| Error::invalid_length(len, &self))?;
+                            len += 1;
+                        )+
+                        if seq.next_element::<IgnoredAny>()?.is_some() {
+                            return Err(Error::invalid_length(len + 1, &self));
+                        }
+                        Ok($ty::new($($field),+))
+                    }
+                }
+
+                deserializer.deserialize_tuple($len, ComponentsVisitor)
+            }
+        }
+    };
 }
-
-impl From<Row> for Vector3 {
-    fn from(row: Row) -> Self {
-        Self::new(row[0], row[1], row[2])
-    }
+#[cfg(feature = "serde")]
+pub(crate) use serde_vector;
+
+vector_types! {
+    f32, Matrix, Row,
+    glam: (Vec2, Vec3, Vec4),
+    glium: (F32F32, F32F32F32, F32F32F32F32),
+
+    /// Laid out as four `f32`s and 16 byte aligned, like `XMVECTOR`. Only x and y are meaningful,
+    /// the hidden z and w lanes are zero.
+    #[repr(C, align(16))]
+    struct Vector2;
+
+    /// Laid out as four `f32`s and 16 byte aligned, like `XMVECTOR`. Only x, y and z are
+    /// meaningful, the hidden w lane is zero.
+    ///
+    /// The hidden w is padding, not a homogeneous coordinate. `transform` treats a `Vector3` as a
+    /// point, as if w were 1. Use `to_homogeneous_dir` and transform a `Vector4` for directions.
+    #[repr(C, align(16))]
+    struct Vector3;
+
+    /// Laid out as four `f32`s and 16 byte aligned, like `XMVECTOR`.
+    #[repr(C, align(16))]
+    struct Vector4;
 }
 
-impl From<Row> for Vector4 {
-    fn from(row: Row) -> Self {
-        Self::new(row[0], row[1], row[2], row[3])
-    }
-}
-
-#[cfg(feature = "glium-support")]
-mod glium_support {
-    use super::{Vector2, Vector3, Vector4};
-    use glium::vertex::{Attribute, AttributeType};
+#[cfg(feature = "glam")]
+mod glam_vec3a_support {
+    use super::Vector3;
 
-    unsafe impl Attribute for Vector2 {
-        fn get_type() -> AttributeType {
-            AttributeType::F32F32
-        }
-    }
-    unsafe impl Attribute for Vector3 {
-        fn get_type() -> AttributeType {
-            AttributeType::F32F32F32
+    impl From<glam::Vec3A> for Vector3 {
+        fn from(v: glam::Vec3A) -> Self {
+            Self::new(v.x, v.y, v.z)
         }
     }
-    unsafe impl Attribute for Vector4 {
-        fn get_type() -> AttributeType {
-            AttributeType::F32F32F32F32
+    impl From<Vector3> for glam::Vec3A {
+        fn from(v: Vector3) -> Self {
+            glam::Vec3A::new(v.x, v.y, v.z)
         }
     }
 }
@@ -0,0 +1,108 @@
+extern crate xmath;
+
+use xmath::batch::{self, Vector3x4};
+use xmath::{Matrix, Vector, Vector3};
+
+/// `count` distinct points.
+fn points(count: usize) -> Vec<Vector3> {
+    (0..count)
+        .map(|i| {
+            let i = i as f32;
+            Vector3::new(i, 0.5 * i - 3.0, 2.0 - i * i * 0.1)
+        })
+        .collect()
+}
+
+fn matrix() -> Matrix {
+    Matrix::scaling(2.0, 0.5, 1.5) * Matrix::rotation_y(0.8) * Matrix::translation(1.0, -2.0, 3.0)
+}
+
+fn assert_near(a: Vector3, b: Vector3) {
+    assert!((a - b).length() < 1e-4, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn round_trip_through_lanes() {
+    let v = [
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(4.0, 5.0, 6.0),
+        Vector3::new(7.0, 8.0, 9.0),
+        Vector3::new(10.0, 11.0, 12.0),
+    ];
+
+    let wide = Vector3x4::from(v);
+
+    assert_eq!(wide.x, [1.0, 4.0, 7.0, 10.0]);
+    assert_eq!(wide.z, [3.0, 6.0, 9.0, 12.0]);
+    assert_eq!(wide.lane(2), v[2]);
+    assert_eq!(<[Vector3; 4]>::from(wide), v);
+}
+
+#[test]
+fn lanes_match_vector3() {
+    let v = points(8);
+    let (a, b) = (Vector3x4::from_slice(&v), Vector3x4::from_slice(&v[4..]));
+
+    let sum = a + b;
+    let scaled = (a - b) * 2.0;
+    let cross = a.cross(&b);
+    let dot = a.dot(&b);
+    for i in 0..4 {
+        assert_eq!(sum.lane(i), v[i] + v[i + 4]);
+        assert_eq!(scaled.lane(i), (v[i] - v[i + 4]) * 2.0);
+        assert_eq!(cross.lane(i), v[i].cross(&v[i + 4]));
+        assert_eq!(dot[i], v[i].dot(&v[i + 4]));
+    }
+    assert_eq!((-a).lane(1), -v[1]);
+}
+
+#[test]
+fn transform_points_matches_vector3_transform() {
+    let m = matrix();
+    // Two full chunks and a remainder
+    let input = points(11);
+    let mut output = vec![Vector3::ZERO; input.len()];
+
+    batch::transform_points(&m, &input, &mut output);
+
+    for (p, transformed) in input.iter().zip(&output) {
+        assert_near(*transformed, p.transform(&m));
+    }
+}
+
+#[test]
+fn transform_normals_ignores_translation() {
+    let m = matrix();
+    let input = points(6);
+    let mut output = vec![Vector3::ZERO; input.len()];
+
+    batch::transform_normals(&m, &input, &mut output);
+
+    for (v, transformed) in input.iter().zip(&output) {
+        let expected = v.to_homogeneous_dir().transform(&m).truncate();
+        assert_near(*transformed, expected);
+    }
+}
+
+#[test]
+fn dot_many_matches_dot() {
+    let a = points(7);
+    let b: Vec<Vector3> = a
+        .iter()
+        .map(|v| v.cross(&Vector3::Y) + Vector3::X)
+        .collect();
+    let mut output = [0.0; 7];
+
+    batch::dot_many(&a, &b, &mut output);
+
+    for i in 0..7 {
+        assert_eq!(output[i], a[i].dot(&b[i]));
+    }
+}
+
+#[test]
+#[should_panic]
+fn transform_points_needs_matching_lengths() {
+    let mut output = [Vector3::ZERO; 2];
+    batch::transform_points(&matrix(), &points(3), &mut output);
+}
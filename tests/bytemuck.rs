@@ -0,0 +1,99 @@
+#![cfg(feature = "bytemuck")]
+
+extern crate xmath;
+
+use xmath::batch::Vector3x4;
+use xmath::packed::{HalfVector2, UByteN4};
+use xmath::{
+    Color, DVector3, DualQuaternion, IVector2, Matrix, Quaternion, Vector, Vector2, Vector3,
+    Vector4,
+};
+
+#[test]
+fn cast_vector3_slice_to_bytes() {
+    let vertices = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)];
+
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(&bytes[0..4], &1.0f32.to_ne_bytes());
+    assert_eq!(&bytes[8..12], &3.0f32.to_ne_bytes());
+    // The hidden w lane
+    assert_eq!(&bytes[12..16], &0.0f32.to_ne_bytes());
+    assert_eq!(&bytes[16..20], &4.0f32.to_ne_bytes());
+}
+
+#[test]
+fn cast_vector2_to_floats() {
+    let v = Vector2::new(1.0, 2.0);
+
+    let floats: [f32; 4] = bytemuck::cast(v);
+
+    assert_eq!(floats, [1.0, 2.0, 0.0, 0.0]);
+}
+
+#[test]
+fn checked_cast_floats_to_vector3() {
+    let v: Vector3 = bytemuck::checked::cast([1.0f32, 2.0, 3.0, 0.0]);
+    assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+
+    // A nonzero hidden lane is rejected
+    assert!(bytemuck::checked::try_cast::<_, Vector3>([1.0f32, 2.0, 3.0, 4.0]).is_err());
+    assert!(bytemuck::checked::try_cast::<_, Vector2>([1.0f32, 2.0, 0.0, 1.0]).is_err());
+}
+
+#[test]
+fn cast_floats_to_vector4() {
+    let floats = [1.0f32, 2.0, 3.0, 4.0];
+
+    let v: Vector4 = bytemuck::cast(floats);
+
+    assert_eq!(v, Vector4::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn cast_matrix_to_floats() {
+    let m = Matrix::translation(1.0, 2.0, 3.0);
+
+    let floats: [f32; 16] = bytemuck::cast(m);
+
+    assert_eq!(&floats[12..16], &[1.0, 2.0, 3.0, 1.0]);
+    assert_eq!(bytemuck::cast::<_, Matrix>(floats), m);
+}
+
+#[test]
+fn zeroed() {
+    use bytemuck::Zeroable;
+
+    assert_eq!(Vector3::zeroed(), Vector3::zero());
+    assert_eq!(Matrix::zeroed(), Matrix::zero());
+    assert_eq!(IVector2::zeroed(), IVector2::zero());
+    assert_eq!(Color::zeroed(), Color::TRANSPARENT);
+}
+
+#[test]
+fn other_types() {
+    let d: [f64; 4] = bytemuck::cast(DVector3::new(1.0, 2.0, 3.0));
+    assert_eq!(d, [1.0, 2.0, 3.0, 0.0]);
+
+    let i: [i32; 2] = bytemuck::cast(IVector2::new(-1, 2));
+    assert_eq!(i, [-1, 2]);
+
+    let c: u32 = bytemuck::cast(UByteN4::from(Vector4::new(1.0, 0.0, 0.0, 1.0)));
+    assert_eq!(c.to_ne_bytes(), [255, 0, 0, 255]);
+
+    let h: [u16; 2] = bytemuck::cast(HalfVector2::from(Vector2::new(1.0, -2.0)));
+    assert_eq!(h, [0x3C00, 0xC000]);
+
+    let q: [f32; 4] = bytemuck::cast(Quaternion::IDENTITY);
+    assert_eq!(q, [0.0, 0.0, 0.0, 1.0]);
+
+    let d: [f32; 8] = bytemuck::cast(DualQuaternion::IDENTITY);
+    assert_eq!(d, [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+
+    let w: [f32; 12] = bytemuck::cast(Vector3x4::splat(Vector3::new(1.0, 2.0, 3.0)));
+    assert_eq!(
+        w,
+        [1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0]
+    );
+}
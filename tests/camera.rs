@@ -0,0 +1,178 @@
+extern crate xmath;
+
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_3, PI, TAU};
+use xmath::{Camera, Matrix, OrbitCamera, Vector, Vector2, Vector3};
+
+fn assert_near(a: Vector3, b: Vector3) {
+    assert!((a - b).length() < 1e-4, "{:?} != {:?}", a, b);
+}
+
+fn assert_matrix_near(a: &Matrix, b: &Matrix) {
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((a[i][j] - b[i][j]).abs() < 1e-4, "{:?} != {:?}", a, b);
+        }
+    }
+}
+
+fn camera() -> Camera {
+    Camera::new(
+        Vector3::new(0.0, 0.0, 5.0),
+        FRAC_PI_3,
+        16.0 / 9.0,
+        0.1,
+        100.0,
+    )
+}
+
+/// Largest normalized device coordinate of the points, seen through the camera.
+fn max_ndc(camera: &Camera, points: &[Vector3]) -> f32 {
+    let m = camera.view_projection();
+    points
+        .iter()
+        .map(|p| {
+            let clip = p.extend(1.0).transform(&m);
+            assert!(clip.w > 0.0, "{:?} is behind the camera", p);
+            (clip.x / clip.w).abs().max((clip.y / clip.w).abs())
+        })
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn view_and_projection() {
+    let mut c = camera();
+    assert_matrix_near(&c.view(), &Matrix::translation(0.0, 0.0, -5.0));
+    assert_eq!(
+        c.projection(),
+        Matrix::perspective_fov(FRAC_PI_3, 16.0 / 9.0, 0.1, 100.0)
+    );
+
+    let focus = Vector3::new(1.0, 2.0, -3.0);
+    c.look_at(focus, Vector3::Y);
+    assert_matrix_near(&c.view(), &Matrix::look_at(c.position, focus, Vector3::Y));
+    assert_near(c.forward(), (focus - c.position).normalize());
+    assert_matrix_near(&c.view_projection(), &(c.view() * c.projection()));
+}
+
+#[test]
+fn fly() {
+    let mut c = camera();
+    c.fly(Vector3::new(0.0, 0.0, 2.0), FRAC_PI_2, 0.0);
+    assert_near(c.forward(), -Vector3::X);
+    assert_near(c.position, Vector3::new(-2.0, 0.0, 5.0));
+
+    c.fly(Vector3::new(1.0, 1.0, 0.0), 0.0, 0.0);
+    assert_near(c.position, Vector3::new(-2.0, 1.0, 4.0));
+
+    // Pitch stops short of straight up, and turning never rolls
+    c.fly(Vector3::ZERO, 0.3, 10.0);
+    assert!(c.forward().y > 0.99 && c.forward().y < 1.0);
+    for i in 0..100 {
+        c.fly(
+            Vector3::ZERO,
+            0.37 * i as f32,
+            if i % 2 == 0 { 0.9 } else { -1.3 },
+        );
+        assert!(c.right().y.abs() < 1e-4);
+        assert!(c.up().y > 0.0);
+    }
+}
+
+#[test]
+fn orbit() {
+    let target = Vector3::new(1.0, 0.0, 0.0);
+    let mut o = OrbitCamera::new(camera(), target);
+    let distance = o.distance();
+    assert_near(o.camera.forward(), (target - o.camera.position).normalize());
+
+    // From (-1, 0, 5) relative to the target, a quarter turn to the right
+    o.orbit(FRAC_PI_2, 0.0);
+    assert_near(o.camera.position, target + Vector3::new(5.0, 0.0, 1.0));
+    o.orbit(0.4, 0.5);
+    assert!(o.camera.position.y < 0.0);
+    assert!((o.distance() - distance).abs() < 1e-4);
+    assert_near(o.camera.forward(), (target - o.camera.position).normalize());
+    assert_matrix_near(
+        &o.view(),
+        &Matrix::look_at(o.camera.position, target, Vector3::Y),
+    );
+}
+
+#[test]
+fn arcball() {
+    let mut o = OrbitCamera::new(camera(), Vector3::ZERO);
+
+    // Dragging right turns the target right, so the camera goes left around it
+    o.arcball(Vector2::ZERO, Vector2::new(0.5, 0.0));
+    assert!(o.camera.position.x < 0.0);
+    assert!((o.distance() - 5.0).abs() < 1e-4);
+    assert_near(o.camera.forward(), -o.camera.position.normalize());
+
+    o.arcball(Vector2::new(0.3, -0.8), Vector2::new(-1.5, 0.4));
+    assert!((o.distance() - 5.0).abs() < 1e-4);
+    assert_near(o.camera.forward(), -o.camera.position.normalize());
+}
+
+#[test]
+fn zoom_and_pan() {
+    let mut o = OrbitCamera::new(camera(), Vector3::ZERO);
+    o.zoom(0.5);
+    assert_near(o.camera.position, Vector3::new(0.0, 0.0, 2.5));
+
+    o.pan(Vector2::new(1.0, -2.0));
+    assert_near(o.target, Vector3::new(2.5, -5.0, 0.0));
+    assert_near(o.camera.position, Vector3::new(2.5, -5.0, 2.5));
+}
+
+#[test]
+fn fit_sphere() {
+    let mut c = camera();
+    c.look_at(Vector3::new(-1.0, -1.0, -1.0), Vector3::Y);
+    let (center, radius) = (Vector3::new(3.0, 1.0, -2.0), 2.0);
+    c.fit_sphere(center, radius);
+
+    assert_near(c.forward(), (center - c.position).normalize());
+    let mut silhouette = Vec::new();
+    for i in 0..64 {
+        for j in 0..32 {
+            let (theta, phi) = (j as f32 / 31.0 * PI, i as f32 / 64.0 * TAU);
+            let dir = Vector3::new(
+                theta.sin() * phi.cos(),
+                theta.sin() * phi.sin(),
+                theta.cos(),
+            );
+            silhouette.push(center + dir * radius);
+        }
+    }
+    let max = max_ndc(&c, &silhouette);
+    assert!(max < 1.0 + 1e-4 && max > 0.99, "{}", max);
+
+    let mut o = OrbitCamera::new(camera(), Vector3::ZERO);
+    o.fit_sphere(center, radius);
+    assert_eq!(o.target, center);
+}
+
+#[test]
+fn fit_box() {
+    let mut c = camera();
+    c.look_at(Vector3::new(2.0, -1.0, -4.0), Vector3::Y);
+    let (min, max) = (Vector3::new(-1.0, 0.0, -3.0), Vector3::new(2.0, 0.5, -1.0));
+    c.fit_box(min, max);
+
+    let corners: Vec<Vector3> = (0..8)
+        .map(|i| {
+            Vector3::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        })
+        .collect();
+    let ndc = max_ndc(&c, &corners);
+    assert!((ndc - 1.0).abs() < 1e-4, "{}", ndc);
+
+    // Tighter than the bounding sphere
+    let mut sphere = c;
+    sphere.fit_sphere((min + max) * 0.5, (max - min).length() * 0.5);
+    assert!(max_ndc(&sphere, &corners) < 1.0);
+}
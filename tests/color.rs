@@ -0,0 +1,201 @@
+extern crate xmath;
+
+use xmath::{packed, Color, Vector4};
+
+fn assert_near(a: Color, b: Color, tolerance: f32) {
+    assert!(
+        (a.r - b.r).abs() <= tolerance
+            && (a.g - b.g).abs() <= tolerance
+            && (a.b - b.b).abs() <= tolerance
+            && (a.a - b.a).abs() <= tolerance,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn new_color() {
+    let c = Color::new(0.1, 0.2, 0.3, 0.4);
+
+    assert_eq!(c.r, 0.1);
+    assert_eq!(c.g, 0.2);
+    assert_eq!(c.b, 0.3);
+    assert_eq!(c.a, 0.4);
+    assert_eq!(Color::rgb(0.1, 0.2, 0.3).a, 1.0);
+}
+
+#[test]
+fn named_colors() {
+    assert_eq!(Color::RED, Color::rgb(1.0, 0.0, 0.0));
+    assert_eq!(Color::TRANSPARENT.a, 0.0);
+    assert_eq!(
+        packed::Color::from(Color::CORNFLOWER_BLUE).to_argb(),
+        0xFF6495ED
+    );
+    assert_eq!(packed::Color::from(Color::GREEN).to_argb(), 0xFF008000);
+}
+
+#[test]
+fn srgb_to_rgb_known_values() {
+    let c = Color::new(0.5, 0.04045, 1.0, 0.5).srgb_to_rgb();
+
+    assert_near(c, Color::new(0.2140411, 0.0031308, 1.0, 0.5), 1.0e-6);
+    assert_eq!(Color::BLACK.srgb_to_rgb(), Color::BLACK);
+    assert_eq!(Color::WHITE.srgb_to_rgb(), Color::WHITE);
+}
+
+#[test]
+fn rgb_to_srgb_known_values() {
+    let c = Color::new(0.5, 0.0031308, 0.18, 0.5).rgb_to_srgb();
+
+    assert_near(c, Color::new(0.735357, 0.04045, 0.4613561, 0.5), 1.0e-6);
+}
+
+#[test]
+fn srgb_curve_is_linear_near_black() {
+    let c = Color::rgb(0.002, 0.0, 0.0);
+
+    assert_eq!(c.rgb_to_srgb().r, 0.002 * 12.92);
+    assert_eq!(Color::rgb(0.03, 0.0, 0.0).srgb_to_rgb().r, 0.03 / 12.92);
+}
+
+#[test]
+fn srgb_round_trip() {
+    for i in 0..=255 {
+        let x = i as f32 / 255.0;
+        let c = Color::new(x, x, x, x);
+
+        assert_near(c.srgb_to_rgb().rgb_to_srgb(), c, 1.0e-5);
+    }
+}
+
+#[test]
+fn srgb_saturates() {
+    let c = Color::new(-1.0, 2.0, 0.0, 3.0).srgb_to_rgb();
+
+    assert_eq!(c, Color::new(0.0, 1.0, 0.0, 3.0));
+}
+
+#[test]
+fn rgb_to_hsl_known_values() {
+    assert_near(
+        Color::rgb(0.2, 0.4, 0.6).rgb_to_hsl(),
+        Color::rgb(210.0 / 360.0, 0.5, 0.4),
+        1.0e-6,
+    );
+    assert_near(Color::RED.rgb_to_hsl(), Color::rgb(0.0, 1.0, 0.5), 1.0e-6);
+    assert_near(
+        Color::MAGENTA.rgb_to_hsl(),
+        Color::rgb(300.0 / 360.0, 1.0, 0.5),
+        1.0e-6,
+    );
+    assert_near(
+        Color::GRAY.rgb_to_hsl(),
+        Color::rgb(0.0, 0.0, 128.0 / 255.0),
+        1.0e-6,
+    );
+}
+
+#[test]
+fn rgb_to_hsv_known_values() {
+    assert_near(
+        Color::rgb(0.2, 0.4, 0.6).rgb_to_hsv(),
+        Color::rgb(210.0 / 360.0, 2.0 / 3.0, 0.6),
+        1.0e-6,
+    );
+    assert_near(
+        Color::ORANGE.rgb_to_hsv(),
+        Color::rgb(165.0 / 255.0 / 6.0, 1.0, 1.0),
+        1.0e-6,
+    );
+    assert_near(Color::BLACK.rgb_to_hsv(), Color::rgb(0.0, 0.0, 0.0), 0.0);
+}
+
+#[test]
+fn hsl_and_hsv_round_trip() {
+    let colors = [
+        Color::new(0.2, 0.4, 0.6, 0.5),
+        Color::new(0.9, 0.1, 0.3, 1.0),
+        Color::new(0.5, 0.5, 0.2, 0.0),
+        Color::new(0.1, 0.8, 0.7, 1.0),
+        Color::new(0.6, 0.2, 0.9, 1.0),
+        Color::new(0.3, 0.3, 0.3, 1.0),
+        Color::CORNFLOWER_BLUE,
+    ];
+
+    for c in colors {
+        assert_near(c.rgb_to_hsl().hsl_to_rgb(), c, 1.0e-6);
+        assert_near(c.rgb_to_hsv().hsv_to_rgb(), c, 1.0e-6);
+    }
+}
+
+#[test]
+fn yuv_known_values() {
+    assert_near(Color::WHITE.rgb_to_yuv(), Color::rgb(1.0, 0.0, 0.0), 1.0e-6);
+    assert_near(
+        Color::RED.rgb_to_yuv(),
+        Color::rgb(0.299, -0.147, 0.615),
+        1.0e-6,
+    );
+    assert_near(
+        Color::BLUE.rgb_to_yuv_hd(),
+        Color::rgb(0.0722, 0.4351, -0.0564),
+        1.0e-6,
+    );
+}
+
+#[test]
+fn yuv_round_trip() {
+    let c = Color::new(0.2, 0.4, 0.6, 0.5);
+
+    assert_near(c.rgb_to_yuv().yuv_to_rgb(), c, 2.0e-3);
+    assert_near(c.rgb_to_yuv_hd().yuv_to_rgb_hd(), c, 2.0e-3);
+}
+
+#[test]
+fn adjust_saturation() {
+    let c = Color::new(0.2, 0.4, 0.6, 0.5);
+    let luminance = 0.2 * 0.2125 + 0.4 * 0.7154 + 0.6 * 0.0721;
+
+    assert_near(
+        c.adjust_saturation(0.0),
+        Color::new(luminance, luminance, luminance, 0.5),
+        1.0e-6,
+    );
+    assert_eq!(c.adjust_saturation(1.0), c);
+}
+
+#[test]
+fn adjust_contrast() {
+    let c = Color::new(0.25, 0.5, 1.0, 0.5);
+
+    assert_eq!(c.adjust_contrast(0.0), Color::new(0.5, 0.5, 0.5, 0.5));
+    assert_eq!(c.adjust_contrast(2.0), Color::new(0.0, 0.5, 1.5, 0.5));
+}
+
+#[test]
+fn negative() {
+    let c = Color::new(0.25, 0.5, 1.0, 0.75);
+
+    assert_eq!(c.negative(), Color::new(0.75, 0.5, 0.0, 0.75));
+}
+
+#[test]
+fn modulate_and_operators() {
+    let a = Color::new(0.5, 0.5, 1.0, 1.0);
+    let b = Color::new(0.5, 1.0, 0.25, 0.5);
+
+    assert_eq!(a.modulate(&b), Color::new(0.25, 0.5, 0.25, 0.5));
+    assert_eq!(a + b, Color::new(1.0, 1.5, 1.25, 1.5));
+    assert_eq!(a - b, Color::new(0.0, -0.5, 0.75, 0.5));
+    assert_eq!(a * 2.0, Color::new(1.0, 1.0, 2.0, 2.0));
+}
+
+#[test]
+fn vector4_conversion() {
+    let v = Vector4::new(0.1, 0.2, 0.3, 0.4);
+
+    assert_eq!(Color::from(v), Color::new(0.1, 0.2, 0.3, 0.4));
+    assert_eq!(Vector4::from(Color::from(v)), v);
+}
@@ -0,0 +1,170 @@
+extern crate xmath;
+
+use xmath::curve::{
+    BSpline, CatmullRom, ControlPoint, CubicBezier, Curve, Hermite, QuadraticBezier,
+};
+use xmath::{Vector, Vector2, Vector3, Vector4};
+
+fn assert_near<V: ControlPoint + std::fmt::Debug>(a: V, b: V, tolerance: f32) {
+    assert!((a - b).length() < tolerance, "{:?} != {:?}", a, b);
+}
+
+/// Compares the derivative with central differences, away from the segment joins.
+fn check_derivative<V: ControlPoint + std::fmt::Debug>(curve: &impl Curve<V>) {
+    let h = 1e-3;
+    for t in [0.1, 0.3, 0.45, 0.7, 0.9] {
+        let difference = (curve.eval(t + h) - curve.eval(t - h)) * (0.5 / h);
+        let derivative = curve.derivative(t);
+        assert_near(derivative, difference, 1e-2 * (1.0 + derivative.length()));
+    }
+}
+
+fn points() -> [Vector3; 6] {
+    [
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 2.0, 0.0),
+        Vector3::new(3.0, 2.5, 1.0),
+        Vector3::new(4.0, 0.0, 2.0),
+        Vector3::new(7.0, -1.0, 2.0),
+        Vector3::new(8.0, 1.0, 0.0),
+    ]
+}
+
+#[test]
+fn bezier() {
+    let q = QuadraticBezier::new(
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 2.0),
+        Vector2::new(2.0, 0.0),
+    );
+    assert_eq!(q.eval(0.0), q.p0);
+    assert_eq!(q.eval(1.0), q.p2);
+    assert_near(q.eval(0.5), Vector2::new(1.0, 1.0), 1e-6);
+    assert_near(q.derivative(0.0), Vector2::new(2.0, 4.0), 1e-6);
+    check_derivative(&q);
+
+    let [p0, p1, p2, p3, ..] = points();
+    let c = CubicBezier::new(p0, p1, p2, p3);
+    assert_eq!(c.eval(0.0), p0);
+    assert_near(c.eval(1.0), p3, 1e-6);
+    assert_near(c.derivative(1.0), (p3 - p2) * 3.0, 1e-5);
+    check_derivative(&c);
+}
+
+#[test]
+fn hermite_is_a_cubic_bezier() {
+    let (p0, t0) = (
+        Vector4::new(1.0, 0.0, 0.0, 1.0),
+        Vector4::new(0.0, 3.0, 0.0, 0.0),
+    );
+    let (p1, t1) = (
+        Vector4::new(0.0, 1.0, 2.0, 1.0),
+        Vector4::new(-3.0, 0.0, 1.0, 0.0),
+    );
+    let h = Hermite::new(p0, t0, p1, t1);
+    let c = CubicBezier::new(p0, p0 + t0 * (1.0 / 3.0), p1 - t1 * (1.0 / 3.0), p1);
+
+    for t in [0.0, 0.25, 0.6, 1.0] {
+        assert_near(h.eval(t), c.eval(t), 1e-5);
+        assert_near(h.derivative(t), c.derivative(t), 1e-5);
+    }
+    assert_near(h.derivative(0.0), t0, 1e-6);
+}
+
+#[test]
+fn catmull_rom() {
+    let p = points();
+    for spline in [CatmullRom::uniform(&p), CatmullRom::centripetal(&p)] {
+        // Passes through the inner points
+        for i in 0..=3 {
+            assert_near(spline.eval(i as f32 / 3.0), p[i + 1], 1e-5);
+        }
+        check_derivative(&spline);
+    }
+
+    // The uniform spline is a Hermite curve with tangents from the neighbors
+    let spline = CatmullRom::uniform(&p);
+    let h = Hermite::new(p[2], (p[3] - p[1]) * 0.5, p[3], (p[4] - p[2]) * 0.5);
+    for u in [0.0, 0.3, 0.8] {
+        let t = (1.0 + u) / 3.0;
+        assert_near(spline.eval(t), h.eval(u), 1e-5);
+        assert_near(spline.derivative(t), h.derivative(u) * 3.0, 1e-4);
+    }
+}
+
+#[test]
+fn b_spline() {
+    let p = points();
+    let spline = BSpline::new(&p);
+    assert_eq!(spline.points().len(), 6);
+
+    assert_near(
+        spline.eval(0.0),
+        (p[0] + p[1] * 4.0 + p[2]) * (1.0 / 6.0),
+        1e-6,
+    );
+    assert_near(
+        spline.eval(1.0),
+        (p[3] + p[4] * 4.0 + p[5]) * (1.0 / 6.0),
+        1e-5,
+    );
+    assert_near(spline.derivative(0.0), (p[2] - p[0]) * 1.5, 1e-5);
+    check_derivative(&spline);
+}
+
+#[test]
+fn arc_length() {
+    // Uneven speed along a straight line
+    let line = QuadraticBezier::new(
+        Vector3::ZERO,
+        Vector3::new(0.5, 0.0, 0.0),
+        Vector3::new(4.0, 0.0, 0.0),
+    );
+    assert!((line.length() - 4.0).abs() < 1e-5);
+    let t = line.t_at_arc_length(1.0);
+    assert_near(line.eval(t), Vector3::new(1.0, 0.0, 0.0), 1e-4);
+    assert!((line.arc_length(t) - 1.0).abs() < 1e-4);
+    assert_eq!(line.t_at_arc_length(-1.0), 0.0);
+    assert_eq!(line.t_at_arc_length(5.0), 1.0);
+
+    // Usual cubic approximation of a quarter circle, off by 3e-4 in radius
+    let k = 0.5522848;
+    let arc = CubicBezier::new(
+        Vector2::new(1.0, 0.0),
+        Vector2::new(1.0, k),
+        Vector2::new(k, 1.0),
+        Vector2::new(0.0, 1.0),
+    );
+    assert!((arc.length() - std::f32::consts::FRAC_PI_2).abs() < 1e-3);
+
+    let p = points();
+    let spline = CatmullRom::centripetal(&p);
+    let half = spline.t_at_arc_length(0.5 * spline.length());
+    assert!((spline.arc_length(half) - 0.5 * spline.length()).abs() < 1e-3);
+}
+
+#[test]
+fn closest_point() {
+    let line = QuadraticBezier::new(
+        Vector3::ZERO,
+        Vector3::new(0.5, 0.0, 0.0),
+        Vector3::new(4.0, 0.0, 0.0),
+    );
+    let t = line.closest_point(&Vector3::new(3.0, 5.0, -1.0));
+    assert_near(line.eval(t), Vector3::new(3.0, 0.0, 0.0), 1e-3);
+    assert_eq!(line.closest_point(&Vector3::new(-2.0, 1.0, 0.0)), 0.0);
+
+    // The point is closest where the curve is perpendicular to the offset
+    let p = points();
+    let spline = BSpline::new(&p);
+    let target = Vector3::new(3.0, 3.0, 3.0);
+    let t = spline.closest_point(&target);
+    let offset = spline.eval(t) - target;
+    assert!(
+        offset
+            .normalize()
+            .dot(&spline.derivative(t).normalize())
+            .abs()
+            < 1e-3
+    );
+}
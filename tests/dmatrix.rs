@@ -0,0 +1,91 @@
+extern crate xmath;
+
+use xmath::{DMatrix, DVector4, Matrix, Vector};
+
+#[test]
+fn create_zero_filled_dmatrix() {
+    let m = DMatrix::zero();
+
+    for row in 0..4 {
+        assert_eq!(m[row], [0.0; 4]);
+    }
+}
+
+#[test]
+fn new_dmatrix() {
+    #[rustfmt::skip]
+    let m = DMatrix::new(
+        1.0, 2.0, 3.0, 4.0,
+        5.0, 6.0, 7.0, 8.0,
+        9.0, 10.0, 11.0, 12.0,
+        13.0, 14.0, 15.0, 16.0,
+    );
+
+    assert_eq!(m[0], [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(m[1], [5.0, 6.0, 7.0, 8.0]);
+    assert_eq!(m[2], [9.0, 10.0, 11.0, 12.0]);
+    assert_eq!(m[3], [13.0, 14.0, 15.0, 16.0]);
+}
+
+#[test]
+fn multiply_with_identity() {
+    let m = DMatrix::translation(1.0, 2.0, 3.0) * DMatrix::rotation_z(0.5);
+
+    assert_eq!(m * DMatrix::identity(), m);
+    assert_eq!(DMatrix::identity() * m, m);
+}
+
+#[test]
+fn translate_dvector4() {
+    let m = DMatrix::translation(1.0e9, 2.0, 3.0);
+
+    let v = DVector4::new(0.5, 0.0, 0.0, 1.0).transform(&m);
+
+    assert_eq!(v, DVector4::new(1.0e9 + 0.5, 2.0, 3.0, 1.0));
+}
+
+#[test]
+fn from_matrix() {
+    let m = Matrix::rotation_y(0.3);
+
+    let d = DMatrix::from(m);
+
+    for row in 0..4 {
+        for col in 0..4 {
+            assert_eq!(d[row][col], m[row][col] as f64);
+        }
+    }
+    assert_eq!(d.to_f32(), m);
+}
+
+#[test]
+fn to_f32_rounds_to_nearest() {
+    let d = DMatrix::rotation_x(1.0);
+
+    let m = d.to_f32();
+
+    assert_eq!(m[1][1], 1.0f64.cos() as f32);
+    assert_eq!(m[1][2], 1.0f64.sin() as f32);
+}
+
+#[test]
+fn inverse_undoes_the_matrix() {
+    let m = DMatrix::scaling(2.0, 3.0, 0.5)
+        * DMatrix::rotation_x(0.7)
+        * DMatrix::translation(1.0, -2.0, 3.0);
+
+    let product = m * m.inverse().unwrap();
+
+    let identity = DMatrix::identity();
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!(
+                (product[i][j] - identity[i][j]).abs() < 1e-12,
+                "{:?}",
+                product
+            );
+        }
+    }
+    assert_eq!(m.determinant(), 3.0);
+    assert_eq!(DMatrix::scaling(0.0, 1.0, 1.0).inverse(), None);
+}
@@ -0,0 +1,185 @@
+extern crate xmath;
+
+use std::f64;
+use xmath::{DMatrix, DVector2, DVector3, DVector4, Vector, Vector2, Vector3, Vector4};
+
+#[test]
+fn new_dvector2() {
+    let v = DVector2::new(1.2, 3.4);
+
+    assert_eq!(v.x, 1.2);
+    assert_eq!(v.y, 3.4);
+}
+
+#[test]
+fn new_dvector3() {
+    let v = DVector3::new(1.2, 3.4, 5.6);
+
+    assert_eq!(v.x, 1.2);
+    assert_eq!(v.y, 3.4);
+    assert_eq!(v.z, 5.6);
+}
+
+#[test]
+fn new_dvector4() {
+    let v = DVector4::new(1.2, 3.4, 5.6, 7.8);
+
+    assert_eq!(v.x, 1.2);
+    assert_eq!(v.y, 3.4);
+    assert_eq!(v.z, 5.6);
+    assert_eq!(v.w, 7.8);
+}
+
+#[test]
+fn epsilon_of_dvector3() {
+    let v = DVector3::epsilon();
+
+    assert_eq!(v.x, f64::EPSILON);
+    assert_eq!(v.y, f64::EPSILON);
+    assert_eq!(v.z, f64::EPSILON);
+}
+
+#[test]
+fn dot_of_dvector3() {
+    let v1 = DVector3::new(1.1, 2.2, 3.3);
+    let v2 = DVector3::new(4.4, 5.5, 6.6);
+
+    let f = v1.dot(&v2);
+
+    assert_eq!(f, 1.1 * 4.4 + 2.2 * 5.5 + 3.3 * 6.6);
+}
+
+#[test]
+fn length_of_dvector2() {
+    let v = DVector2::new(3.0, 4.0);
+
+    assert_eq!(v.length_sq(), 25.0);
+    assert_eq!(v.length(), 5.0);
+}
+
+#[test]
+fn normalize_dvector4() {
+    let v = DVector4::new(0.0, 100.0, 0.0, 0.0).normalize();
+
+    assert_eq!(v, DVector4::new(0.0, 1.0, 0.0, 0.0));
+}
+
+#[test]
+fn cross_product() {
+    let a = DVector3::new(1.0, 2.0, 3.0);
+    let b = DVector3::new(4.0, 5.0, 6.0);
+
+    let c = a.cross(&b);
+
+    assert_eq!(c, DVector3::new(-3.0, 6.0, -3.0));
+}
+
+#[test]
+fn transform_dvector3() {
+    #[rustfmt::skip]
+    let matrix = DMatrix::new(
+        2.0, 3.0, 5.0, 7.0,
+        11.0, 13.0, 17.0, 19.0,
+        23.0, 29.0, 31.0, 37.0,
+        41.0, 43.0, 47.0, 53.0,
+    );
+
+    let origin = DVector3::new(100.0, 10000.0, 1000000.0);
+
+    let transformed = origin.transform(&matrix);
+
+    assert_eq!(
+        transformed,
+        DVector3::new(23110241.0, 29130343.0, 31170547.0)
+    );
+}
+
+#[test]
+fn keeps_precision_lost_by_f32() {
+    let big = 16777216.0;
+    let v = DVector3::new(big, 0.0, 0.0) + DVector3::new(1.0, 0.0, 0.0);
+
+    assert_eq!(v.x, big + 1.0);
+    assert_eq!(v.to_f32().x, big as f32);
+}
+
+#[test]
+fn operators_of_dvector2() {
+    let v1 = DVector2::new(1.0, 2.0);
+    let v2 = DVector2::new(10.0, 20.0);
+
+    assert_eq!(v1 + v2, DVector2::new(11.0, 22.0));
+    assert_eq!(v2 - v1, DVector2::new(9.0, 18.0));
+    assert_eq!(v1 * v2, DVector2::new(10.0, 40.0));
+    assert_eq!(v2 / v1, DVector2::new(10.0, 10.0));
+    assert_eq!(v1 * 2.0, DVector2::new(2.0, 4.0));
+    assert_eq!(2.0 * v1, DVector2::new(2.0, 4.0));
+    assert_eq!(-v1, DVector2::new(-1.0, -2.0));
+}
+
+#[test]
+fn from_vector2() {
+    let v = DVector2::from(Vector2::new(0.1, -2.5));
+
+    assert_eq!(v.x, 0.1f32 as f64);
+    assert_eq!(v.y, -2.5);
+}
+
+#[test]
+fn from_vector3() {
+    let v = DVector3::from(Vector3::new(0.1, -2.5, 3.0));
+
+    assert_eq!(v.x, 0.1f32 as f64);
+    assert_eq!(v.y, -2.5);
+    assert_eq!(v.z, 3.0);
+}
+
+#[test]
+fn from_vector4() {
+    let v = DVector4::from(Vector4::new(0.1, -2.5, 3.0, 4.0));
+
+    assert_eq!(v.x, 0.1f32 as f64);
+    assert_eq!(v.y, -2.5);
+    assert_eq!(v.z, 3.0);
+    assert_eq!(v.w, 4.0);
+}
+
+#[test]
+fn f32_round_trip_is_lossless() {
+    let v2 = Vector2::new(0.1, 1.0e-30);
+    let v3 = Vector3::new(0.1, 1.0e-30, 3.0e30);
+    let v4 = Vector4::new(0.1, 1.0e-30, 3.0e30, -7.7);
+
+    assert_eq!(DVector2::from(v2).to_f32(), v2);
+    assert_eq!(DVector3::from(v3).to_f32(), v3);
+    assert_eq!(DVector4::from(v4).to_f32(), v4);
+}
+
+#[test]
+fn to_f32_rounds_to_nearest() {
+    let v = DVector4::new(0.1, 1.0 / 3.0, 1.0e300, -1.0e-300);
+
+    let v = v.to_f32();
+
+    assert_eq!(v.x, 0.1f32);
+    assert_eq!(v.y, 1.0f32 / 3.0);
+    assert!(v.z.is_infinite());
+    assert_eq!(v.w, 0.0);
+}
+
+#[test]
+fn swizzles() {
+    let v = DVector3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.zx(), DVector2::new(3.0, 1.0));
+    assert_eq!(v.swizzle_const::<2, 1, 0>(), v.zyx());
+}
+
+#[test]
+fn dimension_conversions() {
+    let v = DVector3::from((1.0, 2.0, 3.0));
+    assert_eq!(v.to_homogeneous_point(), DVector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(v.truncate().extend(3.0), v);
+
+    let a: [f64; 3] = v.into();
+    assert_eq!(DVector3::from(a), v);
+}
@@ -0,0 +1,156 @@
+extern crate xmath;
+
+use xmath::{
+    DVector3, Matrix, Matrix3x3, Matrix3x4, Matrix4x3, ParseError, Vector2, Vector3, Vector4,
+};
+
+#[test]
+fn display_vector() {
+    assert_eq!(Vector2::new(1.0, 2.5).to_string(), "(1, 2.5)");
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).to_string(), "(1, 2, 3)");
+    assert_eq!(
+        format!("{:.2}", Vector4::new(1.0, 2.0, 3.0, 4.0)),
+        "(1.00, 2.00, 3.00, 4.00)"
+    );
+    assert_eq!(
+        format!("{:e}", Vector2::new(1500.0, 0.25)),
+        "(1.5e3, 2.5e-1)"
+    );
+    assert_eq!(
+        format!("{:.1}", DVector3::new(0.25, 1.0, -2.0)),
+        "(0.2, 1.0, -2.0)"
+    );
+}
+
+#[test]
+fn display_matrix() {
+    let m = Matrix::translation(1.0, 2.0, 3.0);
+
+    assert_eq!(
+        m.to_string(),
+        "[1, 0, 0, 0]\n[0, 1, 0, 0]\n[0, 0, 1, 0]\n[1, 2, 3, 1]"
+    );
+    assert_eq!(
+        format!("{:5.1}", Matrix::translation(10.0, 0.0, 0.0))
+            .lines()
+            .last(),
+        Some("[ 10.0,   0.0,   0.0,   1.0]")
+    );
+}
+
+#[test]
+fn display_small_matrices() {
+    let m = Matrix::translation(1.0, 2.0, 3.0);
+
+    assert_eq!(
+        Matrix3x3::IDENTITY.to_string(),
+        "[1, 0, 0]\n[0, 1, 0]\n[0, 0, 1]"
+    );
+    assert_eq!(
+        Matrix4x3::from(m).to_string(),
+        "[1, 0, 0]\n[0, 1, 0]\n[0, 0, 1]\n[1, 2, 3]"
+    );
+    assert_eq!(
+        format!("{:.1}", Matrix3x4::from(m)),
+        "[1.0, 0.0, 0.0, 1.0]\n[0.0, 1.0, 0.0, 2.0]\n[0.0, 0.0, 1.0, 3.0]"
+    );
+}
+
+#[test]
+fn parse_vector() {
+    let expected = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!("(1, 2, 3)".parse(), Ok(expected));
+    assert_eq!("[1 2 3]".parse(), Ok(expected));
+    assert_eq!(" 1,2 ,3 ".parse(), Ok(expected));
+    assert_eq!("(1.5e1, -2)".parse(), Ok(Vector2::new(15.0, -2.0)));
+    assert_eq!("1 2 3".parse(), Ok(DVector3::new(1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn parse_vector_errors() {
+    assert_eq!(
+        "(1, 2)".parse::<Vector3>(),
+        Err(ParseError::WrongCount {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        "1 2 3 4".parse::<Vector3>(),
+        Err(ParseError::WrongCount {
+            expected: 3,
+            found: 4
+        })
+    );
+    assert_eq!("(1, 2, 3".parse::<Vector3>(), Err(ParseError::Brackets));
+    assert_eq!("(1, 2, 3]".parse::<Vector3>(), Err(ParseError::Brackets));
+    assert_eq!("((1, 2, 3))".parse::<Vector3>(), Err(ParseError::Brackets));
+    assert!(matches!(
+        "(1, two, 3)".parse::<Vector3>(),
+        Err(ParseError::InvalidNumber(_))
+    ));
+    for empty in ["1,,2,3", "(1, 2, 3,)", ",1 2 3", "1 2 3,"] {
+        assert!(
+            matches!(empty.parse::<Vector3>(), Err(ParseError::InvalidNumber(_))),
+            "{}",
+            empty
+        );
+    }
+    assert_eq!("(1 2)(3)".parse::<Vector3>(), Err(ParseError::Brackets));
+    assert_eq!("(1 2) 3".parse::<Vector3>(), Err(ParseError::Brackets));
+}
+
+#[test]
+fn parse_matrix() {
+    let m = Matrix::translation(1.0, 2.0, 3.0);
+
+    assert_eq!(m.to_string().parse(), Ok(m));
+    assert_eq!("[[1 0 0 0] [0 1 0 0] [0 0 1 0] [1 2 3 1]]".parse(), Ok(m));
+    assert_eq!("1 0 0 0 0 1 0 0 0 0 1 0 1 2 3 1".parse(), Ok(m));
+    assert_eq!(
+        "[1 0 0 0] [0 1 0 0 0 0 1 0] [1 2 3 1]".parse::<Matrix>(),
+        Err(ParseError::Brackets)
+    );
+    assert_eq!(
+        "[1 0 0 0 0 1 0 0] [0 0 1 0 1 2 3 1]".parse::<Matrix>(),
+        Err(ParseError::Brackets)
+    );
+    assert_eq!(
+        "[1 0 0 0]".parse::<Matrix>(),
+        Err(ParseError::WrongCount {
+            expected: 16,
+            found: 4
+        })
+    );
+}
+
+#[test]
+fn parse_small_matrices() {
+    let m = Matrix::translation(1.0, 2.0, 3.0) * Matrix::scaling(2.0, 0.5, 4.0);
+    let m3 = Matrix3x3::from(m);
+    let m43 = Matrix4x3::from(m);
+    let m34 = Matrix3x4::from(m);
+
+    assert_eq!(m3.to_string().parse(), Ok(m3));
+    assert_eq!(m43.to_string().parse(), Ok(m43));
+    assert_eq!(m34.to_string().parse(), Ok(m34));
+    assert_eq!("[[1 0 0] [0 1 0] [0 0 1]]".parse(), Ok(Matrix3x3::IDENTITY));
+    assert_eq!("1 0 0 0 1 0 0 0 1".parse(), Ok(Matrix3x3::IDENTITY));
+    assert_eq!(
+        "[1 0 0 0] [0 1 0 0] [0 0 1 0]".parse::<Matrix4x3>(),
+        Err(ParseError::Brackets)
+    );
+    assert_eq!(
+        "1 0 0 0 1 0 0 0 1".parse::<Matrix3x4>(),
+        Err(ParseError::WrongCount {
+            expected: 12,
+            found: 9
+        })
+    );
+}
+
+#[test]
+fn parse_error_display() {
+    let e = "(1, 2)".parse::<Vector3>().unwrap_err();
+    assert_eq!(e.to_string(), "expected 3 components, found 2");
+}
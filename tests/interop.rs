@@ -0,0 +1,253 @@
+#![cfg(any(
+    feature = "mint",
+    feature = "glam",
+    feature = "nalgebra",
+    feature = "cgmath"
+))]
+
+extern crate xmath;
+
+#[allow(unused_imports)]
+use xmath::{DMatrix, DVector3, Matrix, Quaternion, Vector, Vector2, Vector3, Vector4};
+
+/// Rotation followed by a translation, so a transposed conversion would show.
+#[allow(dead_code)]
+fn sample_matrix() -> Matrix {
+    Matrix::rotation_z(0.5) * Matrix::translation(1.0, 2.0, 3.0)
+}
+
+/// Same rotation as `sample_matrix`.
+#[allow(dead_code)]
+fn sample_quaternion() -> Quaternion {
+    Quaternion::from_axis_angle(Vector3::Z, 0.5)
+}
+
+#[allow(dead_code)]
+fn assert_near(a: Vector4, b: Vector4) {
+    assert!((a - b).length() < 1e-5, "{:?} != {:?}", a, b);
+}
+
+#[cfg(feature = "mint")]
+mod mint_interop {
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        let v: mint::Vector3<f32> = Vector3::new(1.0, 2.0, 3.0).into();
+        assert_eq!(
+            v,
+            mint::Vector3 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0
+            }
+        );
+        assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+
+        let v: mint::Vector2<f32> = Vector2::new(1.0, 2.0).into();
+        assert_eq!(Vector2::from(v), Vector2::new(1.0, 2.0));
+        let v: mint::Vector4<f32> = Vector4::new(1.0, 2.0, 3.0, 4.0).into();
+        assert_eq!(Vector4::from(v), Vector4::new(1.0, 2.0, 3.0, 4.0));
+
+        let v: mint::Vector3<f64> = DVector3::new(1.0, 2.0, 3.0).into();
+        assert_eq!(DVector3::from(v), DVector3::new(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn matrix_translation_is_last_column() {
+        let m = Matrix::translation(1.0, 2.0, 3.0);
+
+        let column: mint::ColumnMatrix4<f32> = m.into();
+        assert_eq!(
+            column.w,
+            mint::Vector4 {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                w: 1.0
+            }
+        );
+
+        let row: mint::RowMatrix4<f32> = m.into();
+        assert_eq!(
+            row.x,
+            mint::Vector4 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+                w: 1.0
+            }
+        );
+    }
+
+    #[test]
+    fn matrix_roundtrip() {
+        let m = sample_matrix();
+        assert_eq!(Matrix::from(mint::ColumnMatrix4::from(m)), m);
+        assert_eq!(Matrix::from(mint::RowMatrix4::from(m)), m);
+
+        let d = DMatrix::from(m);
+        assert_eq!(DMatrix::from(mint::ColumnMatrix4::from(d)), d);
+    }
+
+    #[test]
+    fn quaternion() {
+        let q: mint::Quaternion<f32> = sample_quaternion().into();
+        assert_eq!(q.s, sample_quaternion().w);
+        assert_eq!(q.v.z, sample_quaternion().z);
+        assert_eq!(Quaternion::from(q), sample_quaternion());
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_interop {
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        assert_eq!(
+            glam::Vec2::from(Vector2::new(1.0, 2.0)),
+            glam::Vec2::new(1.0, 2.0)
+        );
+        assert_eq!(
+            glam::Vec3::from(Vector3::new(1.0, 2.0, 3.0)),
+            glam::Vec3::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            Vector3::from(glam::Vec3A::new(1.0, 2.0, 3.0)),
+            Vector3::new(1.0, 2.0, 3.0)
+        );
+        assert_eq!(
+            Vector4::from(glam::Vec4::new(1.0, 2.0, 3.0, 4.0)),
+            Vector4::new(1.0, 2.0, 3.0, 4.0)
+        );
+        assert_eq!(
+            DVector3::from(glam::DVec3::new(1.0, 2.0, 3.0)),
+            DVector3::new(1.0, 2.0, 3.0)
+        );
+    }
+
+    #[test]
+    fn matrix_transforms_the_same() {
+        let m = sample_matrix();
+        let g = glam::Mat4::from(m);
+
+        let p = Vector4::new(4.0, 5.0, 6.0, 1.0);
+        let expected = p.transform(&m);
+        let actual = g * glam::Vec4::from(p);
+
+        assert_near(Vector4::from(actual), expected);
+        assert_eq!(Matrix::from(g), m);
+        assert_eq!(
+            DMatrix::from(glam::DMat4::from(DMatrix::from(m))),
+            DMatrix::from(m)
+        );
+    }
+
+    #[test]
+    fn quaternion_rotates_the_same() {
+        let q = glam::Quat::from(sample_quaternion());
+        let v = Vector3::new(4.0, 5.0, 6.0);
+
+        let actual = Vector3::from(q * glam::Vec3::from(v));
+        assert_near(
+            actual.extend(0.0),
+            sample_quaternion().rotate(&v).extend(0.0),
+        );
+        assert_eq!(Quaternion::from(q), sample_quaternion());
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop {
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        let v = nalgebra::Vector3::from(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(v, nalgebra::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            Vector2::from(nalgebra::Vector2::new(1.0, 2.0)),
+            Vector2::new(1.0, 2.0)
+        );
+        assert_eq!(
+            Vector4::from(nalgebra::Vector4::new(1.0, 2.0, 3.0, 4.0)),
+            Vector4::new(1.0, 2.0, 3.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn matrix_transforms_the_same() {
+        let m = sample_matrix();
+        let n = nalgebra::Matrix4::from(m);
+
+        let p = Vector4::new(4.0, 5.0, 6.0, 1.0);
+        let expected = p.transform(&m);
+        let actual = n * nalgebra::Vector4::from(p);
+
+        assert_near(Vector4::from(actual), expected);
+        assert_eq!(n[(0, 3)], 1.0);
+        assert_eq!(Matrix::from(n), m);
+    }
+
+    #[test]
+    fn quaternion_rotates_the_same() {
+        let q = nalgebra::UnitQuaternion::new_unchecked(sample_quaternion().into());
+        let v = Vector3::new(4.0, 5.0, 6.0);
+
+        let actual = Vector3::from(q * nalgebra::Vector3::from(v));
+        assert_near(
+            actual.extend(0.0),
+            sample_quaternion().rotate(&v).extend(0.0),
+        );
+        assert_eq!(Quaternion::from(q.into_inner()), sample_quaternion());
+    }
+}
+
+#[cfg(feature = "cgmath")]
+mod cgmath_interop {
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        let v = cgmath::Vector3::from(Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(v, cgmath::Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(Vector3::from(v), Vector3::new(1.0, 2.0, 3.0));
+        assert_eq!(
+            Vector2::from(cgmath::Vector2::new(1.0, 2.0)),
+            Vector2::new(1.0, 2.0)
+        );
+        assert_eq!(
+            Vector4::from(cgmath::Vector4::new(1.0, 2.0, 3.0, 4.0)),
+            Vector4::new(1.0, 2.0, 3.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn matrix_transforms_the_same() {
+        let m = sample_matrix();
+        let c = cgmath::Matrix4::from(m);
+
+        let p = Vector4::new(4.0, 5.0, 6.0, 1.0);
+        let expected = p.transform(&m);
+        let actual = c * cgmath::Vector4::from(p);
+
+        assert_near(Vector4::from(actual), expected);
+        assert_eq!(c.w, cgmath::Vector4::new(1.0, 2.0, 3.0, 1.0));
+        assert_eq!(Matrix::from(c), m);
+    }
+
+    #[test]
+    fn quaternion_rotates_the_same() {
+        let q = cgmath::Quaternion::from(sample_quaternion());
+        let v = Vector3::new(4.0, 5.0, 6.0);
+
+        let actual = Vector3::from(q * cgmath::Vector3::from(v));
+        assert_near(
+            actual.extend(0.0),
+            sample_quaternion().rotate(&v).extend(0.0),
+        );
+        assert_eq!(Quaternion::from(q), sample_quaternion());
+    }
+}
@@ -0,0 +1,180 @@
+extern crate xmath;
+
+use xmath::{
+    DVector3, IVector2, IVector3, IVector4, Rounding, UVector2, UVector3, UVector4, Vector2,
+    Vector3, Vector4,
+};
+
+#[test]
+fn new_ivector2() {
+    let v = IVector2::new(1, -2);
+
+    assert_eq!(v.x, 1);
+    assert_eq!(v.y, -2);
+}
+
+#[test]
+fn new_ivector3() {
+    let v = IVector3::new(1, -2, 3);
+
+    assert_eq!(v.x, 1);
+    assert_eq!(v.y, -2);
+    assert_eq!(v.z, 3);
+}
+
+#[test]
+fn new_uvector4() {
+    let v = UVector4::new(1, 2, 3, 4);
+
+    assert_eq!(v.x, 1);
+    assert_eq!(v.y, 2);
+    assert_eq!(v.z, 3);
+    assert_eq!(v.w, 4);
+}
+
+#[test]
+fn zero_one_and_replicate() {
+    assert_eq!(IVector3::zero(), IVector3::new(0, 0, 0));
+    assert_eq!(UVector2::one(), UVector2::new(1, 1));
+    assert_eq!(IVector4::replicate(-7), IVector4::new(-7, -7, -7, -7));
+}
+
+#[test]
+fn arithmetic_of_ivector3() {
+    let a = IVector3::new(7, -8, 9);
+    let b = IVector3::new(2, 3, -4);
+
+    assert_eq!(a + b, IVector3::new(9, -5, 5));
+    assert_eq!(a - b, IVector3::new(5, -11, 13));
+    assert_eq!(a * b, IVector3::new(14, -24, -36));
+    assert_eq!(a / b, IVector3::new(3, -2, -2));
+    assert_eq!(a % b, IVector3::new(1, -2, 1));
+    assert_eq!(a * 2, IVector3::new(14, -16, 18));
+    assert_eq!(2 * a, IVector3::new(14, -16, 18));
+    assert_eq!(a / 2, IVector3::new(3, -4, 4));
+    assert_eq!(-a, IVector3::new(-7, 8, -9));
+}
+
+#[test]
+fn assign_operators_of_uvector2() {
+    let mut v = UVector2::new(10, 20);
+
+    v += UVector2::new(1, 2);
+    assert_eq!(v, UVector2::new(11, 22));
+    v -= UVector2::new(1, 2);
+    assert_eq!(v, UVector2::new(10, 20));
+    v *= 3;
+    assert_eq!(v, UVector2::new(30, 60));
+    v /= UVector2::new(3, 4);
+    assert_eq!(v, UVector2::new(10, 15));
+    v %= UVector2::new(4, 4);
+    assert_eq!(v, UVector2::new(2, 3));
+}
+
+#[test]
+fn min_max_of_ivector4() {
+    let a = IVector4::new(1, -5, 3, 0);
+    let b = IVector4::new(-1, 5, 3, 2);
+
+    assert_eq!(a.min(&b), IVector4::new(-1, -5, 3, 0));
+    assert_eq!(a.max(&b), IVector4::new(1, 5, 3, 2));
+}
+
+#[test]
+fn clamp_of_uvector3() {
+    let v = UVector3::new(0, 50, 500);
+
+    let v = v.clamp(&UVector3::new(10, 10, 10), &UVector3::new(100, 100, 100));
+
+    assert_eq!(v, UVector3::new(10, 50, 100));
+}
+
+#[test]
+#[should_panic]
+fn clamp_should_fail_when_min_is_more_than_max() {
+    let v = IVector2::new(0, 0);
+    let _ = v.clamp(&IVector2::new(1, 0), &IVector2::new(0, 0));
+}
+
+#[test]
+fn checked_operations() {
+    let a = IVector2::new(i32::MAX, 1);
+    let b = IVector2::new(1, 1);
+
+    assert_eq!(a.checked_add(&b), None);
+    assert_eq!(a.checked_sub(&b), Some(IVector2::new(i32::MAX - 1, 0)));
+    assert_eq!(a.checked_mul(&IVector2::new(2, 1)), None);
+    assert_eq!(a.checked_div(&IVector2::new(1, 0)), None);
+    assert_eq!(UVector2::new(0, 1).checked_sub(&UVector2::new(1, 0)), None);
+}
+
+#[test]
+fn wrapping_operations() {
+    let a = UVector3::new(u32::MAX, 0, 7);
+    let b = UVector3::new(1, 1, 2);
+
+    assert_eq!(a.wrapping_add(&b), UVector3::new(0, 1, 9));
+    assert_eq!(a.wrapping_sub(&b), UVector3::new(u32::MAX - 1, u32::MAX, 5));
+    assert_eq!(a.wrapping_mul(&b), UVector3::new(u32::MAX, 0, 14));
+}
+
+#[test]
+fn from_vector_with_rounding() {
+    let v = Vector4::new(1.5, -1.5, 2.4, -2.6);
+
+    assert_eq!(
+        IVector4::from_vector(&v, Rounding::Round),
+        IVector4::new(2, -2, 2, -3)
+    );
+    assert_eq!(
+        IVector4::from_vector(&v, Rounding::Floor),
+        IVector4::new(1, -2, 2, -3)
+    );
+    assert_eq!(
+        IVector4::from_vector(&v, Rounding::Ceil),
+        IVector4::new(2, -1, 3, -2)
+    );
+    assert_eq!(
+        IVector4::from_vector(&v, Rounding::Trunc),
+        IVector4::new(1, -1, 2, -2)
+    );
+}
+
+#[test]
+fn from_vector_saturates() {
+    let v = Vector3::new(-3.5, 1.0e20, f32::NAN);
+
+    assert_eq!(
+        UVector3::from_vector(&v, Rounding::Round),
+        UVector3::new(0, u32::MAX, 0)
+    );
+    assert_eq!(
+        IVector3::from_vector(&v, Rounding::Round),
+        IVector3::new(-4, i32::MAX, 0)
+    );
+}
+
+#[test]
+fn to_vector() {
+    assert_eq!(IVector2::new(3, -4).to_vector(), Vector2::new(3.0, -4.0));
+    assert_eq!(
+        UVector3::new(3, 4, 5).to_vector(),
+        Vector3::new(3.0, 4.0, 5.0)
+    );
+    assert_eq!(
+        IVector4::new(1, 2, 3, 4).to_vector(),
+        Vector4::new(1.0, 2.0, 3.0, 4.0)
+    );
+}
+
+#[test]
+fn into_dvector_is_lossless() {
+    let v = IVector3::new(i32::MAX, i32::MIN, 16777217);
+
+    let d = DVector3::from(v);
+
+    assert_eq!(
+        d,
+        DVector3::new(i32::MAX as f64, i32::MIN as f64, 16777217.0)
+    );
+}
@@ -1,6 +1,6 @@
 extern crate xmath;
 
-use xmath::Matrix;
+use xmath::{Matrix, Vector, Vector2};
 
 #[test]
 fn create_zero_filled_matrix() {
@@ -74,3 +74,203 @@ fn indexing() {
     let row3 = matrix[3];
     assert_eq!(row3, [41.0, 43.0, 47.0, 53.0]);
 }
+
+#[test]
+fn rows_array_round_trip() {
+    let rows = [
+        [2.0, 3.0, 5.0, 7.0],
+        [11.0, 13.0, 17.0, 19.0],
+        [23.0, 29.0, 31.0, 37.0],
+        [41.0, 43.0, 47.0, 53.0],
+    ];
+
+    let matrix = Matrix::from_rows_array(rows);
+
+    assert_eq!(matrix[1], [11.0, 13.0, 17.0, 19.0]);
+    assert_eq!(matrix.to_rows_array(), rows);
+    assert_eq!(<[[f32; 4]; 4]>::from(matrix), rows);
+}
+
+#[test]
+fn cols_array_round_trip() {
+    let cols = [
+        [2.0, 3.0, 5.0, 7.0],
+        [11.0, 13.0, 17.0, 19.0],
+        [23.0, 29.0, 31.0, 37.0],
+        [41.0, 43.0, 47.0, 53.0],
+    ];
+
+    let matrix = Matrix::from_cols_array(cols);
+
+    assert_eq!(matrix[0], [2.0, 11.0, 23.0, 41.0]);
+    assert_eq!(matrix[3], [7.0, 19.0, 37.0, 53.0]);
+    assert_eq!(matrix.to_cols_array(), cols);
+}
+
+#[test]
+fn cols_array_is_transposed_rows_array() {
+    let matrix = Matrix::translation(1.0, 2.0, 3.0);
+
+    assert_eq!(matrix.to_cols_array(), matrix.transpose().to_rows_array());
+    assert_eq!(matrix.to_cols_array()[0], [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[cfg(feature = "glium-support")]
+#[test]
+fn uniform_layout() {
+    use glium::uniforms::{AsUniformValue, UniformValue};
+    use xmath::UniformLayout;
+
+    let matrix = Matrix::translation(1.0, 2.0, 3.0);
+
+    match matrix.uniform(UniformLayout::Rows).as_uniform_value() {
+        UniformValue::Mat4(m) => assert_eq!(m, matrix.to_rows_array()),
+        _ => unreachable!(),
+    }
+    match matrix.uniform(UniformLayout::Columns).as_uniform_value() {
+        UniformValue::Mat4(m) => assert_eq!(m, matrix.to_cols_array()),
+        _ => unreachable!(),
+    }
+    match matrix.as_uniform_value() {
+        UniformValue::Mat4(m) => assert_eq!(m, matrix.to_rows_array()),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn layout_of_matrix() {
+    use std::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<Matrix>(), 64);
+    assert_eq!(align_of::<Matrix>(), 16);
+}
+
+static VIEW: Matrix = Matrix::translation(1.0, 2.0, 3.0);
+
+#[test]
+fn constants() {
+    assert_eq!(Matrix::IDENTITY, Matrix::identity());
+    assert_eq!(Matrix::ZERO, Matrix::zero());
+    assert_eq!(VIEW * Matrix::IDENTITY, VIEW);
+
+    const M: Matrix = Matrix::from_rows_array(Matrix::IDENTITY.to_rows_array());
+    assert_eq!(M, Matrix::IDENTITY);
+}
+
+fn transform_2d(m: &Matrix, x: f32, y: f32) -> (f32, f32) {
+    let p = Vector2::new(x, y).transform(m);
+    (p.x, p.y)
+}
+
+fn assert_near_2d(a: (f32, f32), b: (f32, f32)) {
+    assert!(
+        (a.0 - b.0).abs() < 1e-5 && (a.1 - b.1).abs() < 1e-5,
+        "{:?} != {:?}",
+        a,
+        b
+    );
+}
+
+#[test]
+fn affine_transformation_2d() {
+    let m = Matrix::affine_transformation_2d(
+        Vector2::new(2.0, 3.0),
+        Vector2::new(1.0, 0.0),
+        std::f32::consts::FRAC_PI_2,
+        Vector2::new(10.0, 0.0),
+    );
+
+    // Scaled to (2, 3), rotated around (1, 0) to (-2, 1), then translated.
+    assert_near_2d(transform_2d(&m, 1.0, 1.0), (8.0, 1.0));
+}
+
+#[test]
+fn transformation_2d() {
+    let scale_around = Matrix::transformation_2d(
+        Vector2::new(1.0, 1.0),
+        0.0,
+        Vector2::new(2.0, 2.0),
+        Vector2::zero(),
+        0.0,
+        Vector2::zero(),
+    );
+    assert_near_2d(transform_2d(&scale_around, 2.0, 1.0), (3.0, 1.0));
+
+    // Stretch along the diagonal only
+    let oriented = Matrix::transformation_2d(
+        Vector2::zero(),
+        std::f32::consts::FRAC_PI_4,
+        Vector2::new(2.0, 1.0),
+        Vector2::zero(),
+        0.0,
+        Vector2::zero(),
+    );
+    assert_near_2d(transform_2d(&oriented, 1.0, 1.0), (2.0, 2.0));
+    assert_near_2d(transform_2d(&oriented, 1.0, -1.0), (1.0, -1.0));
+
+    let args = (
+        Vector2::new(2.0, 3.0),
+        Vector2::new(1.0, 0.0),
+        0.3,
+        Vector2::new(4.0, 5.0),
+    );
+    let affine = Matrix::affine_transformation_2d(args.0, args.1, args.2, args.3);
+    let full = Matrix::transformation_2d(Vector2::zero(), 0.0, args.0, args.1, args.2, args.3);
+    assert_near_2d(
+        transform_2d(&full, 7.0, -2.0),
+        transform_2d(&affine, 7.0, -2.0),
+    );
+}
+
+#[test]
+fn inverse_undoes_the_matrix() {
+    let m = Matrix::scaling(2.0, 3.0, 0.5)
+        * Matrix::rotation_y(0.7)
+        * Matrix::translation(1.0, -2.0, 3.0)
+        * Matrix::perspective_fov(1.0, 1.5, 0.1, 100.0);
+
+    let product = m * m.inverse().unwrap();
+
+    let identity = Matrix::identity();
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!(
+                (product[i][j] - identity[i][j]).abs() < 1e-4,
+                "{:?}",
+                product
+            );
+        }
+    }
+}
+
+#[test]
+fn determinant() {
+    assert_eq!(Matrix::scaling(2.0, 3.0, 4.0).determinant(), 24.0);
+    assert_eq!(Matrix::translation(5.0, 6.0, 7.0).determinant(), 1.0);
+    assert!((Matrix::rotation_z(1.2).determinant() - 1.0).abs() < 1e-5);
+
+    let singular = Matrix::scaling(1.0, 0.0, 1.0);
+    assert_eq!(singular.determinant(), 0.0);
+    assert_eq!(singular.inverse(), None);
+}
+
+#[test]
+fn est_builders_are_close() {
+    let pairs = [
+        (Matrix::rotation_x(0.7), Matrix::rotation_x_est(0.7)),
+        (Matrix::rotation_y(-2.1), Matrix::rotation_y_est(-2.1)),
+        (Matrix::rotation_z(3.0), Matrix::rotation_z_est(3.0)),
+        (
+            Matrix::perspective_fov(1.0, 1.5, 0.1, 100.0),
+            Matrix::perspective_fov_est(1.0, 1.5, 0.1, 100.0),
+        ),
+    ];
+    for (exact, est) in pairs.iter() {
+        for i in 0..4 {
+            for j in 0..4 {
+                let error = (exact[i][j] - est[i][j]).abs();
+                assert!(error <= 2e-5 * exact[i][j].abs().max(1.0), "{:?}", est);
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+extern crate xmath;
+
+use xmath::{Matrix, Matrix3x3, Matrix3x4, Matrix4x3, Vector, Vector3};
+
+fn sample() -> Matrix {
+    Matrix::rotation_y(0.5) * Matrix::rotation_x(0.25) * Matrix::translation(1.0, 2.0, 3.0)
+}
+
+fn assert_near(a: Vector3, b: Vector3) {
+    assert!((a - b).length() < 1e-5, "{:?} != {:?}", a, b);
+}
+
+#[test]
+fn load_store() {
+    let m = sample();
+
+    assert_eq!(Matrix::from(Matrix4x3::from(m)), m);
+    assert_eq!(Matrix::from(Matrix3x4::from(m)), m);
+    assert_eq!(
+        Matrix::from(Matrix3x3::from(m)),
+        Matrix::rotation_y(0.5) * Matrix::rotation_x(0.25)
+    );
+    assert_eq!(Matrix::from(Matrix4x3::IDENTITY), Matrix::IDENTITY);
+    assert_eq!(Matrix::from(Matrix3x4::IDENTITY), Matrix::IDENTITY);
+    assert_eq!(Matrix::from(Matrix3x3::IDENTITY), Matrix::IDENTITY);
+}
+
+#[test]
+fn matrix3x4_is_transposed() {
+    let m = Matrix3x4::from(Matrix::translation(1.0, 2.0, 3.0));
+
+    assert_eq!(m[0], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(m[1], [0.0, 1.0, 0.0, 2.0]);
+    assert_eq!(m[2], [0.0, 0.0, 1.0, 3.0]);
+    assert_eq!(Matrix4x3::from(m)[3], [1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn transform() {
+    let m = sample();
+    let v = Vector3::new(4.0, -5.0, 6.0);
+
+    assert_near(Matrix4x3::from(m).transform(&v), v.transform(&m));
+    assert_near(Matrix3x4::from(m).transform(&v), v.transform(&m));
+
+    let normal = v.to_homogeneous_dir().transform(&m).truncate();
+    assert_near(Matrix4x3::from(m).transform_normal(&v), normal);
+    assert_near(Matrix3x4::from(m).transform_normal(&v), normal);
+    assert_near(Matrix3x3::from(m).transform(&v), normal);
+}
+
+#[test]
+fn multiply() {
+    let a = sample();
+    let b = Matrix::rotation_z(1.0) * Matrix::translation(-3.0, 0.5, 2.0);
+    let expected = a * b;
+
+    let near = |m: Matrix| {
+        let (m, e) = (m.to_rows_array(), expected.to_rows_array());
+        (0..4).all(|i| (0..4).all(|j| (m[i][j] - e[i][j]).abs() < 1e-5))
+    };
+    assert!(near(Matrix::from(Matrix4x3::from(a) * Matrix4x3::from(b))));
+    assert!(near(Matrix::from(Matrix3x4::from(a) * Matrix3x4::from(b))));
+
+    let r = Matrix3x3::from(a) * Matrix3x3::from(b);
+    let r3 = Matrix3x3::from(expected);
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((r[i][j] - r3[i][j]).abs() < 1e-5);
+        }
+    }
+}
+
+#[test]
+fn sizes() {
+    assert_eq!(std::mem::size_of::<Matrix3x3>(), 36);
+    assert_eq!(std::mem::size_of::<Matrix4x3>(), 48);
+    assert_eq!(std::mem::size_of::<Matrix3x4>(), 48);
+}
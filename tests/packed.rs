@@ -0,0 +1,279 @@
+extern crate xmath;
+
+use xmath::packed::*;
+use xmath::{Vector2, Vector3, Vector4};
+
+#[test]
+fn half_from_f32() {
+    assert_eq!(Half::from_f32(0.0).to_bits(), 0x0000);
+    assert_eq!(Half::from_f32(-0.0).to_bits(), 0x8000);
+    assert_eq!(Half::from_f32(1.0).to_bits(), 0x3C00);
+    assert_eq!(Half::from_f32(-2.0).to_bits(), 0xC000);
+    assert_eq!(Half::from_f32(0.1).to_bits(), 0x2E66);
+    assert_eq!(Half::from_f32(65504.0).to_bits(), 0x7BFF);
+    assert_eq!(Half::from_f32(f32::INFINITY).to_bits(), 0x7C00);
+    assert_eq!(Half::from_f32(f32::NEG_INFINITY).to_bits(), 0xFC00);
+}
+
+#[test]
+fn half_from_f32_rounds_to_nearest_even() {
+    // Half way between 1.0 and the next half, 1.0 + 2^-10
+    assert_eq!(Half::from_f32(1.0 + 0.5 / 1024.0).to_bits(), 0x3C00);
+    assert_eq!(Half::from_f32(1.0 + 1.5 / 1024.0).to_bits(), 0x3C02);
+    assert_eq!(Half::from_f32(1.0 + 0.6 / 1024.0).to_bits(), 0x3C01);
+}
+
+#[test]
+fn half_from_f32_overflows_to_infinity() {
+    assert_eq!(Half::from_f32(65519.0).to_bits(), 0x7BFF);
+    assert_eq!(Half::from_f32(65520.0).to_bits(), 0x7C00);
+    assert_eq!(Half::from_f32(1.0e10).to_bits(), 0x7C00);
+    assert_eq!(Half::from_f32(-1.0e10).to_bits(), 0xFC00);
+}
+
+#[test]
+fn half_from_f32_subnormals() {
+    let smallest = 2.0f32.powi(-24);
+
+    assert_eq!(Half::from_f32(smallest).to_bits(), 0x0001);
+    assert_eq!(Half::from_f32(2.0f32.powi(-14)).to_bits(), 0x0400);
+    assert_eq!(Half::from_f32(1023.0 * smallest).to_bits(), 0x03FF);
+    // Exactly half of the smallest subnormal rounds to even, zero
+    assert_eq!(Half::from_f32(0.5 * smallest).to_bits(), 0x0000);
+    assert_eq!(Half::from_f32(0.51 * smallest).to_bits(), 0x0001);
+    assert_eq!(Half::from_f32(1.0e-30).to_bits(), 0x0000);
+    assert_eq!(Half::from_f32(-1.0e-30).to_bits(), 0x8000);
+}
+
+#[test]
+fn half_nan() {
+    let h = Half::from_f32(f32::NAN);
+
+    assert_eq!(h.to_bits() & 0x7C00, 0x7C00);
+    assert_ne!(h.to_bits() & 0x03FF, 0);
+    assert!(h.to_f32().is_nan());
+}
+
+#[test]
+fn half_to_f32() {
+    assert_eq!(Half::from_bits(0x3C00).to_f32(), 1.0);
+    assert_eq!(Half::from_bits(0xC000).to_f32(), -2.0);
+    assert_eq!(Half::from_bits(0x7BFF).to_f32(), 65504.0);
+    assert_eq!(Half::from_bits(0x0001).to_f32(), 2.0f32.powi(-24));
+    assert_eq!(Half::from_bits(0x7C00).to_f32(), f32::INFINITY);
+    assert_eq!(Half::from_bits(0xFC00).to_f32(), f32::NEG_INFINITY);
+    assert!(Half::from_bits(0x8000).to_f32().is_sign_negative());
+}
+
+#[test]
+fn every_half_round_trips() {
+    for bits in 0..=u16::MAX {
+        let h = Half::from_bits(bits);
+        let f = f32::from(h);
+        if f.is_nan() {
+            assert!(Half::from(f).to_f32().is_nan());
+        } else {
+            assert_eq!(Half::from(f).to_bits(), bits);
+        }
+    }
+}
+
+#[test]
+fn half_vectors() {
+    let v2 = Vector2::new(0.5, -3.25);
+    let v4 = Vector4::new(0.5, -3.25, 1024.0, 0.0);
+
+    assert_eq!(Vector2::from(HalfVector2::from(v2)), v2);
+    assert_eq!(Vector4::from(HalfVector4::from(v4)), v4);
+    assert_eq!(HalfVector2::from(v2).y.to_bits(), 0xC280);
+}
+
+#[test]
+fn byte_n4() {
+    let p = ByteN4::from(Vector4::new(1.0, -1.0, 0.5, 2.0));
+
+    assert_eq!(
+        p,
+        ByteN4 {
+            x: 127,
+            y: -127,
+            z: 64,
+            w: 127
+        }
+    );
+
+    let v = Vector4::from(ByteN4 {
+        x: 127,
+        y: -128,
+        z: 0,
+        w: -127,
+    });
+    assert_eq!(v, Vector4::new(1.0, -1.0, 0.0, -1.0));
+}
+
+#[test]
+fn ubyte_n4() {
+    let p = UByteN4::from(Vector4::new(1.0, 0.0, 0.5, -2.0));
+
+    assert_eq!(
+        p,
+        UByteN4 {
+            x: 255,
+            y: 0,
+            z: 128,
+            w: 0
+        }
+    );
+    assert_eq!(
+        Vector4::from(UByteN4 {
+            x: 255,
+            y: 0,
+            z: 51,
+            w: 0
+        }),
+        Vector4::new(1.0, 0.0, 0.2, 0.0)
+    );
+}
+
+#[test]
+fn short_n2() {
+    let p = ShortN2::from(Vector2::new(-1.0, 0.25));
+
+    assert_eq!(p, ShortN2 { x: -32767, y: 8192 });
+    assert_eq!(
+        Vector2::from(ShortN2 {
+            x: -32768,
+            y: 32767
+        }),
+        Vector2::new(-1.0, 1.0)
+    );
+}
+
+#[test]
+fn short_n4_round_trip() {
+    let v = Vector4::new(-1.0, 1.0, 0.0, 0.75);
+
+    let r = Vector4::from(ShortN4::from(v));
+
+    assert!((r.w - v.w).abs() <= 0.5 / 32767.0);
+    assert_eq!(r.x, -1.0);
+    assert_eq!(r.y, 1.0);
+    assert_eq!(r.z, 0.0);
+}
+
+#[test]
+fn ushort_n() {
+    assert_eq!(
+        UShortN2::from(Vector2::new(1.0, 2.0)),
+        UShortN2 { x: 65535, y: 65535 }
+    );
+    assert_eq!(
+        UShortN4::from(Vector4::new(0.0, 1.0, -1.0, 0.5)),
+        UShortN4 {
+            x: 0,
+            y: 65535,
+            z: 0,
+            w: 32768
+        }
+    );
+    assert_eq!(
+        Vector2::from(UShortN2 { x: 65535, y: 0 }),
+        Vector2::new(1.0, 0.0)
+    );
+}
+
+#[test]
+fn dec_n4() {
+    let p = DecN4::from(Vector4::new(1.0, -1.0, 0.0, -1.0));
+
+    assert_eq!(p.v, 0x1FF | (0x201 << 10) | (0x3 << 30));
+    assert_eq!(Vector4::from(p), Vector4::new(1.0, -1.0, 0.0, -1.0));
+}
+
+#[test]
+fn xdec_n4() {
+    let p = XDecN4::from(Vector4::new(-1.0, 0.5, 1.0, 1.0));
+
+    assert_eq!(p.v, 0x201 | (256 << 10) | (0x1FF << 20) | (0x3 << 30));
+
+    let v = Vector4::from(p);
+    assert_eq!(v.x, -1.0);
+    assert_eq!(v.y, 256.0 / 511.0);
+    assert_eq!(v.z, 1.0);
+    assert_eq!(v.w, 1.0);
+}
+
+#[test]
+fn udec_n4() {
+    let p = UDecN4::from(Vector4::new(1.0, 0.0, 0.5, 2.0 / 3.0));
+
+    assert_eq!(p.v, 1023 | (512 << 20) | (2 << 30));
+
+    let v = Vector4::from(p);
+    assert_eq!(v, Vector4::new(1.0, 0.0, 512.0 / 1023.0, 2.0 / 3.0));
+}
+
+#[test]
+fn float_r11g11b10() {
+    let p = FloatR11G11B10::from(Vector3::new(1.0, 2.0, 0.5));
+
+    // 1.0 = exponent 15, 2.0 = 16, 0.5 = 14, all with a zero mantissa
+    assert_eq!(p.v, (15 << 6) | ((16 << 6) << 11) | ((14 << 5) << 22));
+    assert_eq!(Vector3::from(p), Vector3::new(1.0, 2.0, 0.5));
+}
+
+#[test]
+fn float_r11g11b10_clamps() {
+    let p = FloatR11G11B10::from(Vector3::new(-1.0, 1.0e10, f32::NAN));
+
+    let v = Vector3::from(p);
+    assert_eq!(v.x, 0.0);
+    assert_eq!(v.y, f32::INFINITY);
+    assert!(v.z.is_nan());
+
+    // 65024 is the largest finite 11 bit float
+    let p = FloatR11G11B10::from(Vector3::new(65024.0, 1.0 / 64.0 / 16384.0, 0.0));
+    assert_eq!(
+        Vector3::from(p),
+        Vector3::new(65024.0, 1.0 / 64.0 / 16384.0, 0.0)
+    );
+}
+
+#[test]
+fn float_r11g11b10_rounds() {
+    // 1 + 2^-6 is the smallest 11 bit float above 1.0, 1 + 2^-5 for 10 bits
+    let v = Vector3::new(1.0 + 0.4 / 64.0, 1.0 + 0.6 / 64.0, 1.0 + 0.6 / 32.0);
+
+    let v = Vector3::from(FloatR11G11B10::from(v));
+
+    assert_eq!(v, Vector3::new(1.0, 1.0 + 1.0 / 64.0, 1.0 + 1.0 / 32.0));
+}
+
+#[test]
+fn color() {
+    let c = Color::from(Vector4::new(1.0, 0.5, 0.0, 1.0));
+
+    assert_eq!(
+        c,
+        Color {
+            r: 255,
+            g: 128,
+            b: 0,
+            a: 255
+        }
+    );
+    assert_eq!(c.to_argb(), 0xFFFF8000);
+    assert_eq!(Color::from_argb(0xFFFF8000), c);
+    assert_eq!(
+        Vector4::from(Color::from_argb(0x80FF0033)),
+        Vector4::new(1.0, 0.0, 0.2, 128.0 / 255.0)
+    );
+}
+
+#[test]
+fn color_is_stored_as_bgra() {
+    let c = Color::from_argb(0x11223344);
+
+    assert_eq!(std::mem::size_of::<Color>(), 4);
+    assert_eq!(c.to_argb().to_le_bytes(), [c.b, c.g, c.r, c.a]);
+}
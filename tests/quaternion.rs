@@ -0,0 +1,191 @@
+extern crate xmath;
+
+use xmath::{DualQuaternion, Matrix, Quaternion, Vector, Vector3, Vector4};
+
+fn assert_near(a: Vector3, b: Vector3) {
+    assert!((a - b).length() < 1e-4, "{:?} != {:?}", a, b);
+}
+
+fn assert_matrix_near(a: &Matrix, b: &Matrix) {
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((a[i][j] - b[i][j]).abs() < 1e-4, "{:?} != {:?}", a, b);
+        }
+    }
+}
+
+fn sample_points() -> [Vector3; 3] {
+    [
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.5, -2.0, 3.0),
+        Vector3::new(-1.0, 4.0, 0.25),
+    ]
+}
+
+fn rigid() -> Matrix {
+    Matrix::rotation_x(0.3) * Matrix::rotation_y(-1.2) * Matrix::translation(1.0, 2.0, 3.0)
+}
+
+#[test]
+fn agrees_with_matrix_rotations() {
+    let cases = [
+        (
+            Quaternion::from_axis_angle(Vector3::X, 0.7),
+            Matrix::rotation_x(0.7),
+        ),
+        (
+            Quaternion::from_axis_angle(Vector3::Y, -1.1),
+            Matrix::rotation_y(-1.1),
+        ),
+        (
+            Quaternion::from_axis_angle(Vector3::Z * 3.0, 2.5),
+            Matrix::rotation_z(2.5),
+        ),
+    ];
+    for (q, m) in cases {
+        assert_matrix_near(&q.to_matrix(), &m);
+        for p in sample_points() {
+            assert_near(
+                q.rotate(&p),
+                p.to_homogeneous_dir().transform(&m).truncate(),
+            );
+        }
+    }
+}
+
+#[test]
+fn rotation_matrix_round_trip() {
+    let m = Matrix::rotation_z(2.9) * Matrix::rotation_x(-2.0) * Matrix::rotation_y(0.4);
+    let q = Quaternion::from_rotation_matrix(&m);
+
+    assert!((q.length() - 1.0).abs() < 1e-6);
+    assert_matrix_near(&q.to_matrix(), &m);
+    assert_matrix_near(
+        &Quaternion::from_rotation_matrix(&Matrix::IDENTITY).to_matrix(),
+        &Matrix::IDENTITY,
+    );
+}
+
+#[test]
+fn multiplication_order_matches_matrix() {
+    let a = Quaternion::from_axis_angle(Vector3::X, 0.5);
+    let b = Quaternion::from_axis_angle(Vector3::Y, 1.5);
+
+    assert_matrix_near(&(a * b).to_matrix(), &(a.to_matrix() * b.to_matrix()));
+    assert_near(Quaternion::IDENTITY.rotate(&Vector3::ONE), Vector3::ONE);
+
+    let q = a * b;
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_near(q.inverse().rotate(&q.rotate(&v)), v);
+    assert_near(q.conjugate().rotate(&q.rotate(&v)), v);
+}
+
+#[test]
+fn slerp() {
+    let a = Quaternion::from_axis_angle(Vector3::Z, 0.2);
+    let b = Quaternion::from_axis_angle(Vector3::Z, 1.4);
+    let half = Quaternion::from_axis_angle(Vector3::Z, 0.8);
+
+    assert_matrix_near(&a.slerp(&b, 0.0).to_matrix(), &a.to_matrix());
+    assert_matrix_near(&a.slerp(&b, 1.0).to_matrix(), &b.to_matrix());
+    assert_matrix_near(&a.slerp(&b, 0.5).to_matrix(), &half.to_matrix());
+    // Same rotation from the other side of the hypersphere
+    assert_matrix_near(&a.slerp(&-b, 0.5).to_matrix(), &half.to_matrix());
+}
+
+#[test]
+fn dual_quaternion_matrix_round_trip() {
+    let m = rigid();
+    let q = DualQuaternion::from_matrix(&m);
+
+    assert_matrix_near(&q.to_matrix(), &m);
+    assert_matrix_near(&Matrix::from(DualQuaternion::IDENTITY), &Matrix::IDENTITY);
+    assert_near(q.translation(), Vector3::new(1.0, 2.0, 3.0));
+    for p in sample_points() {
+        assert_near(q.transform(&p), p.transform(&m));
+        assert_near(
+            q.transform_normal(&p),
+            p.to_homogeneous_dir().transform(&m).truncate(),
+        );
+    }
+}
+
+#[test]
+fn dual_quaternion_multiplication_order_matches_matrix() {
+    let a = rigid();
+    let b = Matrix::rotation_z(0.8) * Matrix::translation(-4.0, 0.5, 2.0);
+    let q = DualQuaternion::from_matrix(&a) * DualQuaternion::from_matrix(&b);
+
+    assert_matrix_near(&q.to_matrix(), &(a * b));
+    assert_matrix_near(&(q * q.conjugate()).to_matrix(), &Matrix::IDENTITY);
+}
+
+#[test]
+fn dual_quaternion_normalize() {
+    let q = DualQuaternion::from_matrix(&rigid());
+    let scaled = DualQuaternion::new(
+        Quaternion::from(Vector4::from(q.real) * 3.0),
+        Quaternion::from(Vector4::from(q.dual) * 3.0),
+    );
+
+    assert_matrix_near(&scaled.normalize().to_matrix(), &rigid());
+}
+
+#[test]
+fn sclerp() {
+    let rotation = Quaternion::from_axis_angle(Vector3::Z, 1.0);
+    let a = DualQuaternion::IDENTITY;
+    let b = DualQuaternion::from_rotation_translation(rotation, Vector3::new(0.0, 0.0, 4.0));
+
+    assert_matrix_near(&a.sclerp(&b, 0.0).to_matrix(), &a.to_matrix());
+    assert_matrix_near(&a.sclerp(&b, 1.0).to_matrix(), &b.to_matrix());
+    // A screw along z: half the angle, half the distance
+    let half = DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::Z, 0.5),
+        Vector3::new(0.0, 0.0, 2.0),
+    );
+    assert_matrix_near(&a.sclerp(&b, 0.5).to_matrix(), &half.to_matrix());
+    assert_matrix_near(&a.sclerp(&-b, 0.5).to_matrix(), &half.to_matrix());
+
+    // Pure translation
+    let c = DualQuaternion::from_rotation_translation(
+        Quaternion::IDENTITY,
+        Vector3::new(2.0, 0.0, 0.0),
+    );
+    assert_near(
+        a.sclerp(&c, 0.25).translation(),
+        Vector3::new(0.5, 0.0, 0.0),
+    );
+
+    // Endpoints of a general pair
+    let d = DualQuaternion::from_matrix(&rigid());
+    assert_matrix_near(&b.sclerp(&d, 1.0).to_matrix(), &rigid());
+}
+
+#[test]
+fn blend() {
+    let a = DualQuaternion::from_matrix(&rigid());
+    let b = DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::X, 3.0),
+        Vector3::new(0.0, 1.0, 0.0),
+    );
+
+    assert_matrix_near(
+        &DualQuaternion::blend(&[a, a], &[0.3, 0.7]).to_matrix(),
+        &rigid(),
+    );
+    assert_matrix_near(
+        &DualQuaternion::blend(&[a, -a, b], &[0.5, 0.5, 0.0]).to_matrix(),
+        &rigid(),
+    );
+
+    // Twisting around the bone axis keeps points at the same distance from it, unlike blending
+    // matrices.
+    let twist = DualQuaternion::from_rotation_translation(
+        Quaternion::from_axis_angle(Vector3::X, 3.0),
+        Vector3::ZERO,
+    );
+    let p = Vector3::new(0.0, 1.0, 0.0);
+    let blended = DualQuaternion::blend(&[DualQuaternion::IDENTITY, twist], &[0.5, 0.5]);
+    assert!((blended.transform(&p).length() - 1.0).abs() < 1e-5);
+}
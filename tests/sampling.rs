@@ -0,0 +1,180 @@
+extern crate xmath;
+
+use xmath::sampling::{self, Random};
+use xmath::{Matrix, Vector, Vector2, Vector3};
+
+/// Small deterministic generator, so the tests do not need the `rand` feature.
+struct XorShift(u32);
+
+impl Random for XorShift {
+    fn next_f32(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 8) as f32 / (1 << 24) as f32
+    }
+}
+
+const SAMPLES: usize = 20000;
+
+#[test]
+fn unit_vectors_cover_the_sphere() {
+    let mut rng = XorShift(1);
+    let mut mean = Vector3::ZERO;
+    let mut upper = 0;
+    for _ in 0..SAMPLES {
+        let v = sampling::random_unit_vector(&mut rng);
+        assert!((v.length() - 1.0).abs() < 1e-5);
+        mean += v / SAMPLES as f32;
+        upper += (v.x > 0.5) as usize;
+    }
+    assert!(mean.length() < 0.03, "{:?}", mean);
+    // A cap of height 0.5 holds a quarter of the sphere
+    assert!((upper as f32 / SAMPLES as f32 - 0.25).abs() < 0.02);
+}
+
+#[test]
+fn points_in_sphere_fill_the_volume() {
+    let mut rng = XorShift(2);
+    let mut inner = 0;
+    for _ in 0..SAMPLES {
+        let p = sampling::random_in_sphere(&mut rng);
+        assert!(p.length() <= 1.0 + 1e-6);
+        inner += (p.length() < 0.5) as usize;
+    }
+    // Half the radius holds an eighth of the volume
+    assert!((inner as f32 / SAMPLES as f32 - 0.125).abs() < 0.02);
+}
+
+#[test]
+fn concentric_disk() {
+    assert_eq!(
+        sampling::concentric_disk(Vector2::new(0.5, 0.5)),
+        Vector2::new(0.0, 0.0)
+    );
+    let edge = sampling::concentric_disk(Vector2::new(1.0, 0.5));
+    assert!((edge - Vector2::new(1.0, 0.0)).length() < 1e-6);
+    let corner = sampling::concentric_disk(Vector2::new(1.0, 1.0));
+    assert!((corner.length() - 1.0).abs() < 1e-6);
+
+    let mut rng = XorShift(3);
+    let mut inner = 0;
+    for _ in 0..SAMPLES {
+        let p = sampling::random_in_disk(&mut rng);
+        assert!(p.length() <= 1.0 + 1e-6);
+        inner += (p.length() < 0.5) as usize;
+    }
+    assert!((inner as f32 / SAMPLES as f32 - 0.25).abs() < 0.02);
+}
+
+#[test]
+fn cosine_hemisphere_around_normal() {
+    let mut rng = XorShift(4);
+    for normal in [
+        Vector3::Z,
+        -Vector3::Z,
+        Vector3::new(1.0, 2.0, -0.5).normalize(),
+    ] {
+        // The mean cosine of a cosine-weighted hemisphere is 2/3
+        let mut mean_cos = 0.0;
+        for _ in 0..SAMPLES {
+            let v = sampling::random_cosine_hemisphere(&mut rng, &normal);
+            assert!((v.length() - 1.0).abs() < 1e-4, "{:?}", v);
+            let cos = v.dot(&normal);
+            assert!(cos >= -1e-6);
+            mean_cos += cos / SAMPLES as f32;
+        }
+        assert!((mean_cos - 2.0 / 3.0).abs() < 0.01, "{}", mean_cos);
+    }
+}
+
+#[test]
+fn random_rotations_are_uniform() {
+    let mut rng = XorShift(5);
+    let mut mean = Vector3::ZERO;
+    for _ in 0..SAMPLES {
+        let q = sampling::random_rotation(&mut rng);
+        assert!((q.length() - 1.0).abs() < 1e-5);
+        mean += q.rotate(&Vector3::X) / SAMPLES as f32;
+    }
+    assert!(mean.length() < 0.03, "{:?}", mean);
+
+    let m: Matrix = sampling::random_rotation_matrix(&mut rng);
+    assert!((m.determinant() - 1.0).abs() < 1e-4);
+}
+
+#[test]
+fn halton_and_hammersley() {
+    let expected = [0.0, 0.5, 0.25, 0.75, 0.125];
+    for (i, x) in expected.iter().enumerate() {
+        assert_eq!(sampling::halton(i as u32, 0), *x);
+        assert_eq!(sampling::hammersley(i as u32, 8).y, *x);
+    }
+    assert!((sampling::halton(5, 1) - 7.0 / 9.0).abs() < 1e-6);
+    assert!((sampling::halton3(7).z - 11.0 / 25.0).abs() < 1e-6);
+    assert_eq!(sampling::hammersley(3, 4).x, 0.75);
+    assert!(sampling::radical_inverse(3, u32::MAX) < 1.0);
+}
+
+#[test]
+#[should_panic]
+fn radical_inverse_needs_base_2() {
+    sampling::radical_inverse(1, 5);
+}
+
+#[test]
+#[should_panic]
+fn hammersley_needs_points() {
+    sampling::hammersley(0, 0);
+}
+
+#[test]
+fn sobol() {
+    let expected = [
+        [0.0, 0.5, 0.75, 0.25, 0.625, 0.125, 0.375, 0.875],
+        [0.0, 0.5, 0.75, 0.25, 0.375, 0.875, 0.625, 0.125],
+    ];
+    for (i, (y, z)) in expected[0].iter().zip(&expected[1]).enumerate() {
+        let p = sampling::sobol3(i as u32, 0);
+        assert_eq!(p.x, sampling::halton(i as u32, 0));
+        assert_eq!((p.y, p.z), (*y, *z));
+    }
+
+    // Every dimension is stratified: each of 64 intervals gets one of 64 points
+    for dimension in 0..sampling::SOBOL_DIMENSIONS {
+        let mut hits = [0; 64];
+        for i in 0..64 {
+            hits[(sampling::sobol(i, dimension, 0) * 64.0) as usize] += 1;
+        }
+        assert!(hits.iter().all(|&h| h == 1), "{}", dimension);
+    }
+
+    let scrambled = sampling::sobol2(3, 0x1234_5678);
+    assert!(scrambled.x < 1.0 && scrambled.y < 1.0);
+    assert_ne!(scrambled, sampling::sobol2(3, 0));
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn rand_generators_are_random() {
+    struct Counter(u64);
+
+    impl rand_core::RngCore for Counter {
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1);
+            self.0 >> 16
+        }
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dst)
+        }
+    }
+
+    let mut rng = Counter(7);
+    let x = rng.next_f32();
+    assert!((0.0..1.0).contains(&x));
+    let v = sampling::random_unit_vector(&mut rng);
+    assert!((v.length() - 1.0).abs() < 1e-5);
+}
@@ -0,0 +1,48 @@
+extern crate xmath;
+
+use std::f32::consts::PI;
+use xmath::scalar;
+
+fn max_error(f: impl Fn(f32) -> f32, reference: impl Fn(f64) -> f64, min: f32, max: f32) -> f64 {
+    (0..=10000)
+        .map(|i| min + (max - min) * i as f32 / 10000.0)
+        .map(|x| (f(x) as f64 - reference(x as f64)).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn sin_cos_precision() {
+    assert!(max_error(scalar::sin, f64::sin, -PI, PI) < 2e-7);
+    assert!(max_error(scalar::cos, f64::cos, -PI, PI) < 3e-7);
+    assert!(max_error(scalar::sin_est, f64::sin, -PI, PI) < 2e-6);
+    assert!(max_error(scalar::cos_est, f64::cos, -PI, PI) < 1e-5);
+
+    assert!(max_error(|x| scalar::sin_cos(x).0, f64::sin, -PI, PI) < 2e-7);
+    assert!(max_error(|x| scalar::sin_cos_est(x).1, f64::cos, -PI, PI) < 1e-5);
+}
+
+#[test]
+fn sin_cos_large_angles() {
+    assert!(max_error(scalar::sin, f64::sin, -100.0, 100.0) < 1e-5);
+    assert!(max_error(scalar::cos, f64::cos, -100.0, 100.0) < 1e-5);
+}
+
+#[test]
+fn asin_acos_precision() {
+    assert!(max_error(scalar::asin, f64::asin, -1.0, 1.0) < 3e-7);
+    assert!(max_error(scalar::acos, f64::acos, -1.0, 1.0) < 4e-7);
+    assert!(max_error(scalar::asin_est, f64::asin, -1.0, 1.0) < 7e-5);
+    assert!(max_error(scalar::acos_est, f64::acos, -1.0, 1.0) < 7e-5);
+
+    // Out of range inputs are clamped
+    assert_eq!(scalar::asin(2.0), PI / 2.0);
+    assert_eq!(scalar::acos(-2.0), PI);
+}
+
+#[test]
+fn mod_angle() {
+    assert!((scalar::mod_angle(1.5 * PI) - -0.5 * PI).abs() < 1e-6);
+    assert!((scalar::mod_angle(-1.5 * PI) - 0.5 * PI).abs() < 1e-6);
+    assert!((scalar::mod_angle(0.25) - 0.25).abs() < 1e-6);
+    assert!((scalar::mod_angle(0.25 + 20.0 * PI) - 0.25).abs() < 1e-4);
+}
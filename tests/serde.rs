@@ -0,0 +1,138 @@
+#![cfg(feature = "serde")]
+
+extern crate xmath;
+
+use xmath::{DMatrix, DVector3, Matrix, Vector2, Vector3, Vector4};
+
+#[test]
+fn vector2_to_json() {
+    let v = Vector2::new(1.5, -2.0);
+
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1.5,-2.0]");
+}
+
+#[test]
+fn vector3_to_json() {
+    let v = Vector3::new(1.5, -2.0, 3.25);
+
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1.5,-2.0,3.25]");
+}
+
+#[test]
+fn vector4_to_json() {
+    let v = Vector4::new(1.5, -2.0, 3.25, 0.0);
+
+    assert_eq!(serde_json::to_string(&v).unwrap(), "[1.5,-2.0,3.25,0.0]");
+}
+
+#[test]
+fn vectors_from_json() {
+    let v2: Vector2 = serde_json::from_str("[1.5, -2]").unwrap();
+    let v3: Vector3 = serde_json::from_str("[1.5, -2, 3.25]").unwrap();
+    let v4: Vector4 = serde_json::from_str("[1.5, -2, 3.25, 0]").unwrap();
+
+    assert_eq!(v2, Vector2::new(1.5, -2.0));
+    assert_eq!(v3, Vector3::new(1.5, -2.0, 3.25));
+    assert_eq!(v4, Vector4::new(1.5, -2.0, 3.25, 0.0));
+}
+
+#[test]
+fn vector_from_json_with_too_few_components() {
+    let err = serde_json::from_str::<Vector3>("[1.0, 2.0]").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "invalid length 2, expected an array of 3 floats at line 1 column 10"
+    );
+}
+
+#[test]
+fn vector_from_json_with_too_many_components() {
+    let err = serde_json::from_str::<Vector2>("[1.0, 2.0, 0.0]").unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "invalid length 3, expected an array of 2 floats at line 1 column 15"
+    );
+}
+
+#[test]
+fn vector_from_json_with_wrong_type() {
+    assert!(serde_json::from_str::<Vector2>("{\"x\": 1.0, \"y\": 2.0}").is_err());
+    assert!(serde_json::from_str::<Vector2>("[1.0, \"2.0\"]").is_err());
+}
+
+#[test]
+fn matrix_to_json() {
+    let m = Matrix::translation(1.0, 2.0, 3.0);
+
+    assert_eq!(
+        serde_json::to_string(&m).unwrap(),
+        "[1.0,0.0,0.0,0.0,0.0,1.0,0.0,0.0,0.0,0.0,1.0,0.0,1.0,2.0,3.0,1.0]"
+    );
+}
+
+#[test]
+fn matrix_from_json() {
+    let m: Matrix =
+        serde_json::from_str("[1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 2, 3, 1]").unwrap();
+
+    assert_eq!(m, Matrix::translation(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn matrix_from_json_with_wrong_length() {
+    let err = serde_json::from_str::<Matrix>("[1, 0, 0, 0]").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 4, expected an array of 16 floats in row-major order"));
+
+    let err = serde_json::from_str::<Matrix>(&format!("[{}0]", "0, ".repeat(16))).unwrap_err();
+    assert!(err.to_string().starts_with("invalid length 17"));
+}
+
+#[test]
+fn vectors_to_bincode() {
+    let v = Vector3::new(1.5, -2.0, 3.25);
+
+    let bytes = bincode::serialize(&v).unwrap();
+
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(bincode::deserialize::<Vector3>(&bytes).unwrap(), v);
+    assert_eq!(
+        bincode::serialize(&Vector2::new(1.0, 2.0)).unwrap(),
+        [1.0f32.to_le_bytes(), 2.0f32.to_le_bytes()].concat()
+    );
+}
+
+#[test]
+fn matrix_to_bincode() {
+    let m = Matrix::rotation_y(0.5) * Matrix::translation(1.0, 2.0, 3.0);
+
+    let bytes = bincode::serialize(&m).unwrap();
+
+    assert_eq!(bytes.len(), 64);
+    assert_eq!(bincode::deserialize::<Matrix>(&bytes).unwrap(), m);
+}
+
+#[test]
+fn bincode_with_truncated_input() {
+    let bytes = bincode::serialize(&Vector4::new(1.0, 2.0, 3.0, 4.0)).unwrap();
+
+    assert!(bincode::deserialize::<Vector4>(&bytes[..12]).is_err());
+    assert!(bincode::deserialize::<Matrix>(&bytes).is_err());
+}
+
+#[test]
+fn double_precision_types() {
+    let v = DVector3::new(0.1, 1.0e300, -2.0);
+    let m = DMatrix::translation(0.1, 0.2, 0.3);
+
+    let json = serde_json::to_string(&v).unwrap();
+    assert_eq!(json, "[0.1,1e+300,-2.0]");
+    assert_eq!(serde_json::from_str::<DVector3>(&json).unwrap(), v);
+
+    let bytes = bincode::serialize(&m).unwrap();
+    assert_eq!(bytes.len(), 128);
+    assert_eq!(bincode::deserialize::<DMatrix>(&bytes).unwrap(), m);
+}
@@ -0,0 +1,263 @@
+extern crate xmath;
+
+use std::f32::consts::PI;
+use xmath::sh;
+use xmath::{Color, Matrix, Vector, Vector3};
+
+const N: usize = sh::MAX_ORDER * sh::MAX_ORDER;
+
+fn eval(order: usize, dir: Vector3) -> [f32; N] {
+    let mut result = [0.0; N];
+    sh::eval_direction(order, &dir, &mut result);
+    result
+}
+
+fn assert_near(a: &[f32], b: &[f32], tolerance: f32) {
+    for (x, y) in a.iter().zip(b) {
+        assert!((x - y).abs() < tolerance, "{:?} != {:?}", a, b);
+    }
+}
+
+/// Midpoint rule over the directions within `theta_max` of +z, calling `visit` with each
+/// direction and its weight.
+fn quadrature(theta_max: f32, mut visit: impl FnMut(Vector3, f32)) {
+    let (n_theta, n_phi) = (400, 64);
+    let (d_theta, d_phi) = (theta_max / n_theta as f32, 2.0 * PI / n_phi as f32);
+    for i in 0..n_theta {
+        let theta = (i as f32 + 0.5) * d_theta;
+        for j in 0..n_phi {
+            let phi = (j as f32 + 0.5) * d_phi;
+            let dir = Vector3::new(
+                theta.sin() * phi.cos(),
+                theta.sin() * phi.sin(),
+                theta.cos(),
+            );
+            visit(dir, theta.sin() * d_theta * d_phi);
+        }
+    }
+}
+
+/// Projection of `f` restricted to directions within `theta_max` of +z.
+fn project(order: usize, theta_max: f32, f: impl Fn(Vector3) -> f32) -> [f32; N] {
+    let mut result = [0.0; N];
+    quadrature(theta_max, |dir, weight| {
+        let weight = f(dir) * weight;
+        for (r, y) in result.iter_mut().zip(&eval(order, dir)) {
+            *r += weight * y;
+        }
+    });
+    result
+}
+
+/// Exit radiance of a white diffuse surface with the given normal.
+fn exit_radiance(order: usize, light: &[f32], normal: Vector3) -> f32 {
+    const COSINE_LOBE: [f32; 6] = [PI, 2.0 * PI / 3.0, PI / 4.0, 0.0, -PI / 24.0, 0.0];
+    let basis = eval(order, normal);
+    let irradiance: f32 = (0..order * order)
+        .map(|i| COSINE_LOBE[(i as f32).sqrt() as usize] * light[i] * basis[i])
+        .sum();
+    irradiance / PI
+}
+
+fn sample_dir() -> Vector3 {
+    Vector3::new(1.0, 2.0, -3.0).normalize()
+}
+
+#[test]
+fn basis_matches_closed_form() {
+    let d = sample_dir();
+    let (x, y, z) = (d.x, d.y, d.z);
+    let expected = [
+        0.2820948,
+        -0.48860252 * y,
+        0.48860252 * z,
+        -0.48860252 * x,
+        1.0925485 * x * y,
+        -1.0925485 * y * z,
+        0.9461747 * z * z - 0.31539157,
+        -1.0925485 * x * z,
+        0.54627424 * (x * x - y * y),
+    ];
+
+    assert_near(&eval(3, d)[..9], &expected, 1e-6);
+    assert_eq!(eval(3, d)[9..], [0.0; N - 9]);
+    assert_near(
+        &[eval(4, d)[12]],
+        &[z * (1.8658817 * z * z - 1.119529)],
+        1e-6,
+    );
+}
+
+#[test]
+fn basis_is_orthonormal() {
+    let mut gram = [[0.0; N]; N];
+    quadrature(PI, |dir, weight| {
+        let basis = eval(6, dir);
+        for (row, a) in gram.iter_mut().zip(&basis) {
+            for (e, b) in row.iter_mut().zip(&basis) {
+                *e += weight * a * b;
+            }
+        }
+    });
+
+    for (i, row) in gram.iter().enumerate() {
+        let mut expected = [0.0; N];
+        expected[i] = 1.0;
+        assert_near(row, &expected, 1e-3);
+    }
+}
+
+#[test]
+fn dot_follows_addition_theorem() {
+    let (a, b) = (sample_dir(), Vector3::new(-2.0, 0.5, 1.0).normalize());
+    let cos = a.dot(&b);
+    let mut legendre = [1.0, cos, 0.0, 0.0, 0.0, 0.0];
+    for l in 1..5 {
+        legendre[l + 1] =
+            ((2 * l + 1) as f32 * cos * legendre[l] - l as f32 * legendre[l - 1]) / (l + 1) as f32;
+    }
+    let expected: f32 = (0..6)
+        .map(|l| (2 * l + 1) as f32 / (4.0 * PI) * legendre[l])
+        .sum();
+
+    assert!((sh::dot(6, &eval(6, a), &eval(6, b)) - expected).abs() < 1e-5);
+}
+
+#[test]
+fn add_and_scale() {
+    let (a, b) = (eval(4, sample_dir()), eval(4, Vector3::Y));
+    let mut sum = [0.0; N];
+    let mut scaled = [0.0; N];
+    sh::add(4, &a, &b, &mut sum);
+    sh::scale(4, &a, 2.0, &mut scaled);
+
+    assert_eq!(sum[5], a[5] + b[5]);
+    assert_eq!(scaled[7], 2.0 * a[7]);
+    assert_eq!(sum[16..], [0.0; N - 16]);
+    let dot = sh::dot(4, &sum, &scaled);
+    assert!((dot - 2.0 * (sh::dot(4, &a, &a) + sh::dot(4, &a, &b))).abs() < 1e-5);
+}
+
+#[test]
+fn rotate_moves_directions() {
+    let m = Matrix::rotation_x(0.3) * Matrix::rotation_y(1.1) * Matrix::rotation_z(-0.7);
+    for order in sh::MIN_ORDER..=sh::MAX_ORDER {
+        let d = sample_dir();
+        let moved = d.to_homogeneous_dir().transform(&m).truncate();
+        let mut rotated = [0.0; N];
+        sh::rotate(order, &m, &eval(order, d), &mut rotated);
+
+        assert_near(&rotated, &eval(order, moved), 1e-4);
+    }
+
+    let mut same = [0.0; N];
+    sh::rotate(6, &Matrix::IDENTITY, &eval(6, sample_dir()), &mut same);
+    assert_near(&same, &eval(6, sample_dir()), 1e-6);
+}
+
+#[test]
+fn directional_light() {
+    let color = Color::new(1.0, 0.5, 0.25, 1.0);
+    for order in sh::MIN_ORDER..=sh::MAX_ORDER {
+        let (mut r, mut g, mut b) = ([0.0; N], [0.0; N], [0.0; N]);
+        sh::eval_directional_light(
+            order,
+            &sample_dir(),
+            &color,
+            &mut r,
+            Some(&mut g),
+            Some(&mut b),
+        );
+
+        let exit = |c: &[f32]| exit_radiance(order, c, sample_dir());
+        assert_near(&[exit(&r), exit(&g), exit(&b)], &[1.0, 0.5, 0.25], 1e-5);
+    }
+}
+
+#[test]
+fn spherical_light() {
+    let color = Color::new(2.0, 0.0, 0.0, 1.0);
+    let mut r = [0.0; N];
+
+    // Seen from the origin, the sphere covers a cap of half angle asin(1/2)
+    sh::eval_spherical_light(
+        6,
+        &Vector3::new(0.0, 0.0, 2.0),
+        1.0,
+        &color,
+        &mut r,
+        None,
+        None,
+    );
+    let expected = project(6, (0.5f32).asin(), |_| 2.0);
+    assert_near(&r, &expected, 1e-4);
+    assert!((r[0] - 2.0 * PI.sqrt() * (1.0 - 0.75f32.sqrt())).abs() < 1e-5);
+
+    // Inside, constant radiance from everywhere
+    let mut r = [0.0; N];
+    sh::eval_spherical_light(
+        4,
+        &Vector3::new(0.5, 0.0, 0.0),
+        1.0,
+        &color,
+        &mut r,
+        None,
+        None,
+    );
+    let mut expected = [0.0; N];
+    expected[0] = 2.0 * 2.0 * PI.sqrt();
+    assert_near(&r, &expected, 1e-5);
+}
+
+#[test]
+fn cone_light() {
+    let white = Color::new(1.0, 1.0, 1.0, 1.0);
+    let mut r = [0.0; N];
+
+    sh::eval_cone_light(5, &Vector3::Z, 0.6, &white, &mut r, None, None);
+    let cap = project(5, 0.6, |_| 1.0);
+    let scale = r[0] / cap[0];
+    for i in 0..25 {
+        assert!((r[i] - scale * cap[i]).abs() < 1e-4, "{:?} != {:?}", r, cap);
+    }
+    assert!((exit_radiance(5, &r, Vector3::Z) - 1.0).abs() < 1e-5);
+
+    let mut directional = [0.0; N];
+    sh::eval_cone_light(5, &sample_dir(), 0.001, &white, &mut r, None, None);
+    sh::eval_directional_light(5, &sample_dir(), &white, &mut directional, None, None);
+    assert_near(&r, &directional, 1e-4);
+}
+
+#[test]
+fn hemisphere_light() {
+    let (top, bottom) = (
+        Color::new(1.0, 0.5, 0.0, 1.0),
+        Color::new(0.0, 0.5, 1.0, 1.0),
+    );
+    let (mut r, mut g, mut b) = ([0.0; N], [0.0; N], [0.0; N]);
+    sh::eval_hemisphere_light(
+        3,
+        &Vector3::Z,
+        &top,
+        &bottom,
+        &mut r,
+        Some(&mut g),
+        Some(&mut b),
+    );
+
+    let mut expected = project(3, PI, |dir| 0.5 + 0.5 * dir.z);
+    expected[9..].fill(0.0);
+    assert_near(&r, &expected, 1e-3);
+    assert!((exit_radiance(3, &r, Vector3::Z) - 5.0 / 6.0).abs() < 1e-5);
+
+    let mut constant = [0.0; N];
+    constant[0] = 0.5 * 2.0 * PI.sqrt();
+    assert_near(&g, &constant, 1e-6);
+    assert_eq!(b[1..4], [-r[1], -r[2], -r[3]]);
+}
+
+#[test]
+#[should_panic]
+fn unsupported_order() {
+    eval(7, Vector3::Z);
+}
@@ -0,0 +1,129 @@
+extern crate xmath;
+
+use std::f32::consts::FRAC_PI_3;
+use xmath::shadow::{self, ShadowFit};
+use xmath::{Matrix, Vector, Vector3};
+
+fn view() -> Matrix {
+    Matrix::look_to(
+        Vector3::new(3.0, 2.0, 5.0),
+        Vector3::new(-1.0, -0.3, -1.0),
+        Vector3::Y,
+    )
+}
+
+fn projection() -> Matrix {
+    Matrix::perspective_fov(FRAC_PI_3, 16.0 / 9.0, 0.1, 100.0)
+}
+
+fn light_dir() -> Vector3 {
+    Vector3::new(0.4, -1.0, 0.2)
+}
+
+/// Normalized device coordinates of `p` seen through `fit`.
+fn project(fit: &ShadowFit, p: Vector3) -> Vector3 {
+    let clip = p.to_homogeneous_point().transform(&fit.view_projection());
+    clip.truncate() / clip.w
+}
+
+#[test]
+fn cascade_splits() {
+    let mut splits = [0.0; 4];
+
+    shadow::cascade_splits(1.0, 81.0, 0.0, &mut splits);
+    assert_eq!(splits, [21.0, 41.0, 61.0, 81.0]);
+
+    shadow::cascade_splits(1.0, 81.0, 1.0, &mut splits);
+    for (split, expected) in splits.iter().zip([3.0, 9.0, 27.0, 81.0]) {
+        assert!((split - expected).abs() < 1e-4, "{:?}", splits);
+    }
+
+    shadow::cascade_splits(0.1, 100.0, 0.5, &mut splits);
+    assert!(splits.windows(2).all(|w| w[0] < w[1]), "{:?}", splits);
+    assert!(splits[0] > 0.1);
+    assert_eq!(splits[3], 100.0);
+}
+
+#[test]
+fn frustum_corners_lie_on_the_slice() {
+    let (view, projection) = (view(), projection());
+    let corners = shadow::frustum_corners(&view, &projection, 2.0, 10.0);
+
+    let view_projection = view * projection;
+    for (i, corner) in corners.iter().enumerate() {
+        let in_view = corner.transform(&view);
+        let distance = if i < 4 { 2.0 } else { 10.0 };
+        assert!((in_view.z + distance).abs() < 1e-3, "{:?}", in_view);
+
+        let clip = corner.to_homogeneous_point().transform(&view_projection);
+        assert!((clip.x.abs() / clip.w - 1.0).abs() < 1e-3);
+        assert!((clip.y.abs() / clip.w - 1.0).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn fit_slice_encloses_the_slice_tightly() {
+    let (view, projection) = (view(), projection());
+    let fit = shadow::fit_slice(&view, &projection, light_dir(), 2.0, 10.0);
+
+    let mut max = Vector3::ZERO;
+    for corner in shadow::frustum_corners(&view, &projection, 2.0, 10.0) {
+        let p = project(&fit, corner);
+        assert!(
+            p.x.abs() <= 1.0 + 1e-4 && p.y.abs() <= 1.0 + 1e-4,
+            "{:?}",
+            p
+        );
+        assert!(p.z >= -1e-4 && p.z <= 1.0 + 1e-4, "{:?}", p);
+        max = max.max(&Vector3::new(p.x.abs(), p.y.abs(), p.z));
+    }
+    // Every side of the box touches a corner
+    assert!(
+        (max.x - 1.0).abs() < 1e-4 && (max.y - 1.0).abs() < 1e-4,
+        "{:?}",
+        max
+    );
+    assert!((max.z - 1.0).abs() < 1e-4, "{:?}", max);
+}
+
+#[test]
+fn fit_slice_looks_along_the_light() {
+    let fit = shadow::fit_slice(&view(), &projection(), light_dir(), 2.0, 10.0);
+
+    let forward = (-Vector3::Z)
+        .to_homogeneous_dir()
+        .transform(&fit.view.transpose())
+        .truncate();
+    assert!((forward - light_dir().normalize()).length() < 1e-5);
+
+    // Straight down still has a valid up direction
+    let fit = shadow::fit_slice(&view(), &projection(), -Vector3::Y, 2.0, 10.0);
+    assert!(fit.projection()[0][0].is_finite());
+    assert!(fit.left < fit.right && fit.bottom < fit.top);
+}
+
+#[test]
+fn snapped_texels_stay_put_while_the_camera_moves() {
+    let resolution = 1024;
+    let texel_grid = |offset: Vector3| {
+        let view = Matrix::translation(-offset.x, -offset.y, -offset.z) * view();
+        let mut fit = shadow::fit_slice(&view, &projection(), light_dir(), 2.0, 10.0);
+        let unsnapped = fit;
+        fit.snap_to_texels(resolution);
+
+        assert!(fit.left <= unsnapped.left && fit.right >= unsnapped.right);
+        assert!(fit.bottom <= unsnapped.bottom && fit.top >= unsnapped.top);
+        // A texel of the snapped map sits on the world space grid
+        let texel = (unsnapped.right - unsnapped.left) / (resolution - 1) as f32;
+        let texels = (fit.left / texel).round();
+        assert!((fit.left - texels * texel).abs() < 1e-4);
+        // The snapped map is exactly `resolution` texels wide
+        let width = fit.right - fit.left;
+        assert!((width - resolution as f32 * texel).abs() < 1e-4);
+        texel
+    };
+
+    let a = texel_grid(Vector3::ZERO);
+    let b = texel_grid(Vector3::new(0.37, 0.11, -0.52));
+    assert!((a - b).abs() < 1e-5);
+}
@@ -0,0 +1,133 @@
+extern crate xmath;
+
+use xmath::{Matrix, Quaternion, Transform, Vector, Vector3};
+
+fn assert_near(a: Vector3, b: Vector3) {
+    assert!((a - b).length() < 1e-4, "{:?} != {:?}", a, b);
+}
+
+fn assert_matrix_near(a: &Matrix, b: &Matrix) {
+    for i in 0..4 {
+        for j in 0..4 {
+            assert!((a[i][j] - b[i][j]).abs() < 1e-5, "{:?} != {:?}", a, b);
+        }
+    }
+}
+
+fn sample() -> Transform {
+    Transform::new(
+        Vector3::new(1.0, -2.0, 3.0),
+        Quaternion::from_axis_angle(Vector3::new(1.0, 2.0, -0.5), 0.9),
+        Vector3::new(2.0, 0.5, 1.5),
+    )
+}
+
+#[test]
+fn agrees_with_matrix_builders() {
+    let t = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(
+        Transform::from_translation(t).to_matrix(),
+        Matrix::translation(1.0, 2.0, 3.0)
+    );
+    assert_eq!(
+        Transform::from_scale(t).to_matrix(),
+        Matrix::scaling(1.0, 2.0, 3.0)
+    );
+    assert_eq!(Transform::IDENTITY.to_matrix(), Matrix::IDENTITY);
+    assert_eq!(
+        Transform::from_matrix(&Matrix::translation(1.0, 2.0, 3.0)),
+        Transform::from_translation(t)
+    );
+
+    let st = Transform::new(Vector3::new(-1.0, 0.0, 4.0), Quaternion::IDENTITY, t);
+    assert_eq!(
+        st.to_matrix(),
+        Matrix::scaling(1.0, 2.0, 3.0) * Matrix::translation(-1.0, 0.0, 4.0)
+    );
+
+    // The quaternion gives the rotation as 1 - 2y² and 2yw instead of the cos and sin of
+    // `rotation_y`, which round differently, so only the rotation case is compared with a
+    // tolerance
+    let q = Quaternion::from_axis_angle(Vector3::Y, 0.7);
+    let expected = Matrix::scaling(1.0, 2.0, 3.0)
+        * Matrix::rotation_y(0.7)
+        * Matrix::translation(-1.0, 0.0, 4.0);
+    let srt = Transform::new(Vector3::new(-1.0, 0.0, 4.0), q, t);
+    assert_matrix_near(&srt.to_matrix(), &expected);
+    assert_matrix_near(&Matrix::from(srt), &expected);
+}
+
+#[test]
+fn decompose_round_trip() {
+    let m = sample().to_matrix();
+    let t = Transform::from_matrix(&m);
+
+    assert_near(t.translation, sample().translation);
+    assert_near(t.scale, sample().scale);
+    assert_matrix_near(&t.to_matrix(), &m);
+
+    let mirrored = Matrix::scaling(-1.0, 1.0, 1.0) * Matrix::rotation_z(0.3);
+    let t = Transform::from_matrix(&mirrored);
+    assert_near(t.scale, Vector3::new(-1.0, 1.0, 1.0));
+    assert_matrix_near(&t.to_matrix(), &mirrored);
+}
+
+#[test]
+fn transform_points_and_vectors() {
+    let t = sample();
+    let m = t.to_matrix();
+    let p = Vector3::new(0.5, 4.0, -2.0);
+
+    assert_near(t.transform_point(&p), p.transform(&m));
+    assert_near(
+        t.transform_vector(&p),
+        p.to_homogeneous_dir().transform(&m).truncate(),
+    );
+}
+
+#[test]
+fn composition_matches_matrix_multiplication() {
+    let child = sample();
+    let parent = Transform::new(
+        Vector3::new(0.0, 10.0, 0.0),
+        Quaternion::from_axis_angle(Vector3::Z, -1.3),
+        Vector3::replicate(3.0),
+    );
+
+    assert_matrix_near(
+        &(child * parent).to_matrix(),
+        &(child.to_matrix() * parent.to_matrix()),
+    );
+}
+
+#[test]
+fn inverse() {
+    let t = Transform::new(
+        Vector3::new(1.0, -2.0, 3.0),
+        Quaternion::from_axis_angle(Vector3::X, 2.0),
+        Vector3::replicate(0.5),
+    );
+    let p = Vector3::new(0.5, 4.0, -2.0);
+
+    assert_near(t.inverse().transform_point(&t.transform_point(&p)), p);
+    assert_matrix_near(&(t * t.inverse()).to_matrix(), &Matrix::IDENTITY);
+}
+
+#[test]
+fn lerp() {
+    let a = Transform::from_translation(Vector3::new(2.0, 0.0, 0.0));
+    let b = Transform::new(
+        Vector3::new(4.0, 2.0, 0.0),
+        Quaternion::from_axis_angle(Vector3::Z, 1.0),
+        Vector3::replicate(3.0),
+    );
+    let half = a.lerp(&b, 0.5);
+
+    assert_eq!(a.lerp(&b, 0.0), a);
+    assert_near(half.translation, Vector3::new(3.0, 1.0, 0.0));
+    assert_near(half.scale, Vector3::replicate(2.0));
+    assert_matrix_near(
+        &half.rotation.to_matrix(),
+        &Quaternion::from_axis_angle(Vector3::Z, 0.5).to_matrix(),
+    );
+}
@@ -1,7 +1,7 @@
 extern crate xmath;
 
 use std::f32;
-use xmath::{Matrix, Vector, Vector2, Vector3, Vector4};
+use xmath::{DVector2, DVector4, Matrix, Vector, Vector2, Vector3, Vector4};
 
 #[test]
 fn new_vector2() {
@@ -1533,3 +1533,236 @@ fn index_4_for_vector4_should_fail() {
     let v = Vector4::new(1.32, 23.3, -45.4, -53.4);
     let _ = v[4];
 }
+
+#[test]
+fn layout_of_vectors() {
+    use std::mem::{align_of, size_of};
+
+    assert_eq!(size_of::<Vector2>(), 16);
+    assert_eq!(size_of::<Vector3>(), 16);
+    assert_eq!(size_of::<Vector4>(), 16);
+    assert_eq!(align_of::<Vector2>(), 16);
+    assert_eq!(align_of::<Vector3>(), 16);
+    assert_eq!(align_of::<Vector4>(), 16);
+}
+
+static AXES: [Vector3; 3] = [Vector3::X, Vector3::Y, Vector3::Z];
+
+#[test]
+fn constants() {
+    assert_eq!(AXES[0].cross(&AXES[1]), Vector3::Z);
+    assert_eq!(Vector3::NEG_Z, -Vector3::Z);
+    assert_eq!(Vector2::ZERO, Vector2::zero());
+    assert_eq!(Vector3::ONE, Vector3::one());
+    assert_eq!(Vector4::W, Vector4::new(0.0, 0.0, 0.0, 1.0));
+    assert_eq!(Vector4::EPSILON, Vector4::epsilon());
+    assert!(Vector3::INFINITY.is_infinite());
+    assert!(Vector2::NAN.is_nan());
+}
+
+#[test]
+fn per_component_functions() {
+    let v = Vector3::new(0.0, 1.0, 2.0);
+    let near = |a: Vector3, b: Vector3| (a - b).length() < 1e-6;
+
+    assert!(near(v.cos(), Vector3::new(1.0, 1f32.cos(), 2f32.cos())));
+    assert!(near(v.sin(), Vector3::new(0.0, 1f32.sin(), 2f32.sin())));
+    assert!(near(v.tan(), Vector3::new(0.0, 1f32.tan(), 2f32.tan())));
+    assert!(near(v.exp().log(), v));
+    assert!(near(
+        v.pow(&Vector3::replicate(2.0)),
+        Vector3::new(0.0, 1.0, 4.0)
+    ));
+
+    let y = Vector4::new(1.0, 1.0, -1.0, 0.0);
+    let x = Vector4::new(1.0, -1.0, -1.0, 1.0);
+    let expected = Vector4::new(0.25, 0.75, -0.75, 0.0) * f32::consts::PI;
+    assert!((y.atan2(&x) - expected).length() < 1e-6);
+
+    // `==` also compares the hidden lanes, which stay zero where cos and exp would give one.
+    assert_eq!(Vector2::zero().exp(), Vector2::new(1.0, 1.0));
+    assert_eq!(Vector2::zero().cos(), Vector2::one());
+}
+
+#[test]
+fn trigonometry_through_the_trait() {
+    fn polar<V: Vector>(angles: V) -> (V, V) {
+        (angles.cos(), angles.sin())
+    }
+
+    let (x, y) = polar(Vector3::new(0.0, 0.5, 1.0));
+    assert!((y.atan2(&x) - Vector3::new(0.0, 0.5, 1.0)).length() < 1e-6);
+    assert_eq!(polar(Vector2::zero()), (Vector2::one(), Vector2::zero()));
+
+    fn round_trip<V: Vector>(v: V) -> V {
+        v.exp().log().tan()
+    }
+    assert_eq!(round_trip(Vector4::zero()), Vector4::zero());
+}
+
+#[test]
+fn utility_functions() {
+    let v = Vector3::new(-4.0, 0.25, 16.0);
+
+    assert_eq!(v.abs(), Vector3::new(4.0, 0.25, 16.0));
+    assert_eq!(v.abs().sqrt(), Vector3::new(2.0, 0.5, 4.0));
+    assert_eq!(v.reciprocal(), Vector3::new(-0.25, 4.0, 0.0625));
+    assert_eq!(v.abs().reciprocal_sqrt(), Vector3::new(0.5, 2.0, 0.25));
+    assert_eq!(v.abs().log2(), Vector3::new(2.0, -2.0, 4.0));
+    assert_eq!(Vector3::new(2.0, -2.0, 4.0).exp2(), v.abs());
+    assert_eq!(v.saturate(), Vector3::new(0.0, 0.25, 1.0));
+    assert_eq!(v.sum(), 12.25);
+    assert_eq!(Vector4::new(1.0, 2.0, 3.0, 4.0).sum(), 10.0);
+
+    let pi = f32::consts::PI;
+    let angles = Vector4::new(0.5, 1.5 * pi, -1.5 * pi, 0.5 + 4.0 * pi).mod_angles();
+    let expected = Vector4::new(0.5, -0.5 * pi, 0.5 * pi, 0.5);
+    assert!((angles - expected).length() < 1e-5);
+}
+
+#[test]
+fn utility_functions_keep_hidden_lanes_zero() {
+    // 1 / 0 and log2(0) would give infinities in the hidden lanes, and `==` compares them too.
+    assert_eq!(Vector2::one().reciprocal(), Vector2::one());
+    assert_eq!(Vector2::one().reciprocal_sqrt(), Vector2::one());
+    assert_eq!(Vector3::one().log2(), Vector3::zero());
+    assert_eq!(Vector3::zero().exp2(), Vector3::one());
+}
+
+#[test]
+fn utility_functions_through_the_trait() {
+    // Like a shader helper, generic over the vector size
+    fn shade<V: Vector>(v: V) -> V {
+        v.abs()
+            .sqrt()
+            .pow(&V::one())
+            .log2()
+            .exp2()
+            .reciprocal_sqrt()
+            .saturate()
+    }
+
+    assert_eq!(shade(Vector2::new(-16.0, 0.25)), Vector2::new(0.5, 1.0));
+    assert_eq!(
+        shade(Vector3::new(-16.0, 0.25, 256.0)),
+        Vector3::new(0.5, 1.0, 0.25)
+    );
+    assert_eq!(
+        shade(Vector4::new(-16.0, 0.25, 256.0, 1.0)),
+        Vector4::new(0.5, 1.0, 0.25, 1.0)
+    );
+
+    fn wrap<V: Vector>(v: V) -> V {
+        v.mod_angles().reciprocal().reciprocal()
+    }
+    assert_eq!(wrap(Vector2::new(0.5, -0.25)), Vector2::new(0.5, -0.25));
+}
+
+#[test]
+fn swizzle_const() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(
+        v.swizzle_const::<3, 2, 1, 0>(),
+        Vector4::new(4.0, 3.0, 2.0, 1.0)
+    );
+    assert_eq!(v.swizzle_const::<0, 2, 1, 3>(), v.swizzle(0, 2, 1, 3));
+
+    let v = Vector2::new(1.0, 2.0);
+    assert_eq!(v.swizzle_const::<1, 1>(), Vector2::new(2.0, 2.0));
+}
+
+#[test]
+fn permute_const() {
+    let a = Vector3::new(1.0, 2.0, 3.0);
+    let b = Vector3::new(4.0, 5.0, 6.0);
+    assert_eq!(a.permute_const::<0, 5, 4>(&b), Vector3::new(1.0, 5.0, 4.0));
+    assert_eq!(a.permute_const::<0, 5, 4>(&b), a.permute(&b, 0, 5, 4, 0));
+
+    let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let b = Vector4::new(5.0, 6.0, 7.0, 8.0);
+    assert_eq!(
+        a.permute_const::<7, 0, 6, 1>(&b),
+        Vector4::new(8.0, 1.0, 7.0, 2.0)
+    );
+}
+
+#[test]
+fn named_swizzles() {
+    let v = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(v.xy(), Vector2::new(1.0, 2.0));
+    assert_eq!(v.wzy(), Vector3::new(4.0, 3.0, 2.0));
+    assert_eq!(v.wwxx(), Vector4::new(4.0, 4.0, 1.0, 1.0));
+
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    assert_eq!(v.zyx(), Vector3::new(3.0, 2.0, 1.0));
+    assert_eq!(v.xzy(), Vector3::new(1.0, 3.0, 2.0));
+    assert_eq!(v.xyzz(), Vector4::new(1.0, 2.0, 3.0, 3.0));
+
+    assert_eq!(
+        Vector2::new(1.0, 2.0).yxyx(),
+        Vector4::new(2.0, 1.0, 2.0, 1.0)
+    );
+    assert_eq!(
+        DVector4::new(1.0, 2.0, 3.0, 4.0).wx(),
+        DVector2::new(4.0, 1.0)
+    );
+}
+
+#[test]
+fn extend_and_truncate() {
+    let v = Vector2::new(1.0, 2.0);
+    assert_eq!(v.extend(3.0), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(v.extend(3.0).extend(4.0), Vector4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(
+        Vector4::new(1.0, 2.0, 3.0, 4.0).truncate(),
+        Vector3::new(1.0, 2.0, 3.0)
+    );
+    assert_eq!(Vector3::new(1.0, 2.0, 3.0).truncate(), v);
+}
+
+#[test]
+fn homogeneous_coordinates() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let m = Matrix::translation(10.0, 20.0, 30.0);
+
+    assert_eq!(v.to_homogeneous_point(), Vector4::new(1.0, 2.0, 3.0, 1.0));
+    assert_eq!(v.to_homogeneous_dir(), Vector4::new(1.0, 2.0, 3.0, 0.0));
+    assert_eq!(v.to_homogeneous_dir().transform(&m).truncate(), v);
+    assert_eq!(
+        v.to_homogeneous_point().transform(&m).truncate(),
+        v.transform(&m)
+    );
+
+    let projected = Vector4::new(2.0, 4.0, 6.0, 2.0);
+    assert_eq!(Vector3::from_homogeneous(&projected), v);
+}
+
+#[test]
+fn array_and_tuple_conversions() {
+    assert_eq!(Vector2::from([1.0, 2.0]), Vector2::new(1.0, 2.0));
+    assert_eq!(Vector3::from([1.0, 2.0, 3.0]), Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        Vector4::from([1.0, 2.0, 3.0, 4.0]),
+        Vector4::new(1.0, 2.0, 3.0, 4.0)
+    );
+    assert_eq!(Vector3::from((1.0, 2.0, 3.0)), Vector3::new(1.0, 2.0, 3.0));
+
+    let a: [f32; 3] = Vector3::new(1.0, 2.0, 3.0).into();
+    assert_eq!(a, [1.0, 2.0, 3.0]);
+    let a: [f32; 2] = Vector2::new(1.0, 2.0).into();
+    assert_eq!(a, [1.0, 2.0]);
+    let t: (f32, f32, f32, f32) = Vector4::new(1.0, 2.0, 3.0, 4.0).into();
+    assert_eq!(t, (1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn vector2_cross_and_perp() {
+    let a = Vector2::new(2.0, 0.0);
+    let b = Vector2::new(1.0, 3.0);
+
+    assert_eq!(a.cross(&b), 6.0);
+    assert_eq!(b.cross(&a), -6.0);
+    assert_eq!(a.cross(&b), a.extend(0.0).cross(&b.extend(0.0)).z);
+    assert_eq!(b.perp(), Vector2::new(-3.0, 1.0));
+    assert_eq!(b.dot(&b.perp()), 0.0);
+}